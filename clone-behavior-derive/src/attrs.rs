@@ -0,0 +1,79 @@
+use syn::{meta::ParseNestedMeta, Attribute, LitStr, Type};
+use syn::{punctuated::Punctuated, token::Comma, WherePredicate};
+
+
+/// Options given in `#[clone_behavior(..)]` attributes on the type being derived.
+#[derive(Default)]
+pub(crate) struct ContainerAttrs {
+    /// The fastest speed the derived impl may have.
+    pub speed: Option<Type>,
+    /// Bounds replacing every bound which would otherwise be inferred from the fields.
+    pub bound: Option<Punctuated<WherePredicate, Comma>>,
+}
+
+impl ContainerAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut this = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("clone_behavior")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("speed") {
+                    set_once(&mut this.speed, parse_speed(&meta)?, &meta)
+                } else if meta.path.is_ident("bound") {
+                    set_once(&mut this.bound, parse_bound(&meta)?, &meta)
+                } else {
+                    Err(meta.error("unknown `clone_behavior` container attribute"))
+                }
+            })?;
+        }
+
+        Ok(this)
+    }
+}
+
+/// Options given in `#[clone_behavior(..)]` attributes on a field.
+#[derive(Default)]
+pub(crate) struct FieldAttrs {
+    /// The speed at which the field should be cloned, instead of the speed of the derived impl.
+    pub speed: Option<Type>,
+    /// Bounds replacing the bound which would otherwise be inferred from the field's type.
+    pub bound: Option<Punctuated<WherePredicate, Comma>>,
+}
+
+impl FieldAttrs {
+    pub(crate) fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut this = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("clone_behavior")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("speed") {
+                    set_once(&mut this.speed, parse_speed(&meta)?, &meta)
+                } else if meta.path.is_ident("bound") {
+                    set_once(&mut this.bound, parse_bound(&meta)?, &meta)
+                } else {
+                    Err(meta.error("unknown `clone_behavior` field attribute"))
+                }
+            })?;
+        }
+
+        Ok(this)
+    }
+}
+
+/// Parse the `"Speed"` in `speed = "Speed"`.
+fn parse_speed(meta: &ParseNestedMeta<'_>) -> syn::Result<Type> {
+    meta.value()?.parse::<LitStr>()?.parse()
+}
+
+/// Parse the `"T: Bound, U: Bound"` in `bound = "T: Bound, U: Bound"`.
+fn parse_bound(meta: &ParseNestedMeta<'_>) -> syn::Result<Punctuated<WherePredicate, Comma>> {
+    meta.value()?.parse::<LitStr>()?.parse_with(Punctuated::parse_terminated)
+}
+
+fn set_once<T>(slot: &mut Option<T>, value: T, meta: &ParseNestedMeta<'_>) -> syn::Result<()> {
+    if slot.is_some() {
+        return Err(meta.error("duplicate `clone_behavior` attribute"));
+    }
+    *slot = Some(value);
+    Ok(())
+}
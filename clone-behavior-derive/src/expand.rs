@@ -0,0 +1,223 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Data, DeriveInput, Error, Fields, GenericParam, Ident, Path, Type};
+use syn::{punctuated::Punctuated, token::Comma, WherePredicate};
+use syn::visit_mut::{visit_type_mut, VisitMut};
+
+use crate::attrs::{ContainerAttrs, FieldAttrs};
+
+
+/// Which of the cloning traits is being derived.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum CloneTrait {
+    Independent,
+    Mirrored,
+    Mixed,
+}
+
+impl CloneTrait {
+    fn trait_ident(self) -> Ident {
+        let name = match self {
+            Self::Independent => "IndependentClone",
+            Self::Mirrored    => "MirroredClone",
+            Self::Mixed       => "MixedClone",
+        };
+        Ident::new(name, Span::call_site())
+    }
+
+    fn method_ident(self) -> Ident {
+        let name = match self {
+            Self::Independent => "independent_clone",
+            Self::Mirrored    => "mirrored_clone",
+            Self::Mixed       => "mixed_clone",
+        };
+        Ident::new(name, Span::call_site())
+    }
+
+    fn trait_path(self, speed: &Type) -> Path {
+        let trait_ident = self.trait_ident();
+        parse_quote!(::clone_behavior::#trait_ident<#speed>)
+    }
+}
+
+
+pub(crate) fn expand(input: &DeriveInput, clone_trait: CloneTrait) -> syn::Result<TokenStream> {
+    let container_attrs = ContainerAttrs::parse(&input.attrs)?;
+
+    let speed: Type = parse_quote!(__CloneBehaviorSpeed);
+    let trait_path = clone_trait.trait_path(&speed);
+    let method = clone_trait.method_ident();
+
+    let mut generics = input.generics.clone();
+    // Lifetimes must precede every other generic parameter.
+    let first_non_lifetime = generics.params
+        .iter()
+        .position(|param| !matches!(param, GenericParam::Lifetime(_)))
+        .unwrap_or(generics.params.len());
+    generics.params.insert(first_non_lifetime, parse_quote!(#speed: ::clone_behavior::Speed));
+
+    let predicates = &mut generics.make_where_clause().predicates;
+    if let Some(container_speed) = &container_attrs.speed {
+        predicates.push(parse_quote!(#speed: ::clone_behavior::AtLeastAsSlowAs<#container_speed>));
+    }
+    if let Some(bound) = &container_attrs.bound {
+        push_explicit_bound(predicates, bound, &speed);
+    }
+    let infer_bounds = container_attrs.bound.is_none();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let arm = clone_arm(
+                &parse_quote!(Self),
+                &data.fields,
+                clone_trait,
+                &speed,
+                infer_bounds,
+                predicates,
+            )?;
+
+            quote! {
+                match *self {
+                    #arm
+                }
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data.variants
+                .iter()
+                .map(|variant| {
+                    let variant_ident = &variant.ident;
+                    clone_arm(
+                        &parse_quote!(Self::#variant_ident),
+                        &variant.fields,
+                        clone_trait,
+                        &speed,
+                        infer_bounds,
+                        predicates,
+                    )
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            quote! {
+                match *self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                format!("`{}` cannot be derived for unions", clone_trait.trait_ident()),
+            ));
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #trait_path for #name #ty_generics #where_clause {
+            #[inline]
+            fn #method(&self) -> Self {
+                #body
+            }
+        }
+    })
+}
+
+/// Produce a match arm which binds each field of `constructor` by reference, and produces a new
+/// value of `constructor` whose fields are clones of those bindings.
+///
+/// The bounds required to clone each field are added to `predicates`, unless `infer_bounds` is
+/// false or the field's bounds are explicitly given.
+///
+/// The arm should be used to match on a place of type `Self`, not `&Self`.
+fn clone_arm(
+    constructor:  &Path,
+    fields:       &Fields,
+    clone_trait:  CloneTrait,
+    speed:        &Type,
+    infer_bounds: bool,
+    predicates:   &mut Punctuated<WherePredicate, Comma>,
+) -> syn::Result<TokenStream> {
+    let method = clone_trait.method_ident();
+    let members = fields.members().collect::<Vec<_>>();
+    let bindings = (0..members.len())
+        .map(|idx| format_ident!("__binding_{idx}"))
+        .collect::<Vec<_>>();
+
+    let clones = fields
+        .iter()
+        .zip(&bindings)
+        .map(|(field, binding)| {
+            let ty = &field.ty;
+            let field_attrs = FieldAttrs::parse(&field.attrs)?;
+
+            if is_phantom_data(ty) {
+                return Ok(quote!(::core::marker::PhantomData));
+            }
+
+            let field_trait_path = if let Some(field_speed) = &field_attrs.speed {
+                predicates.push(parse_quote!(
+                    #speed: ::clone_behavior::AtLeastAsSlowAs<#field_speed>
+                ));
+                clone_trait.trait_path(field_speed)
+            } else {
+                clone_trait.trait_path(speed)
+            };
+
+            match &field_attrs.bound {
+                Some(bound) => push_explicit_bound(predicates, bound, speed),
+                None if infer_bounds => predicates.push(parse_quote!(#ty: #field_trait_path)),
+                None => {}
+            }
+
+            Ok(quote!(<#ty as #field_trait_path>::#method(#binding)))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        #constructor { #(#members: ref #bindings,)* } => #constructor { #(#members: #clones,)* },
+    })
+}
+
+/// Whether the type is (syntactically) a `PhantomData`, whose clone needs no bounds.
+fn is_phantom_data(ty: &Type) -> bool {
+    if let Type::Path(ty) = ty {
+        ty.qself.is_none()
+            && ty.path.segments.last().is_some_and(|segment| segment.ident == "PhantomData")
+    } else {
+        false
+    }
+}
+
+/// Add bounds given in a `bound` attribute to `predicates`, replacing each `_` type in them with
+/// the speed of the derived impl.
+fn push_explicit_bound(
+    predicates: &mut Punctuated<WherePredicate, Comma>,
+    bound:      &Punctuated<WherePredicate, Comma>,
+    speed:      &Type,
+) {
+    let mut replace_infer = ReplaceInfer(speed);
+
+    for predicate in bound {
+        let mut predicate = predicate.clone();
+        replace_infer.visit_where_predicate_mut(&mut predicate);
+        predicates.push(predicate);
+    }
+}
+
+/// Replaces each `_` type with the given type.
+struct ReplaceInfer<'a>(&'a Type);
+
+impl VisitMut for ReplaceInfer<'_> {
+    fn visit_type_mut(&mut self, i: &mut Type) {
+        if matches!(i, Type::Infer(_)) {
+            i.clone_from(self.0);
+        } else {
+            visit_type_mut(self, i);
+        }
+    }
+}
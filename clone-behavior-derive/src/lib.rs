@@ -0,0 +1,273 @@
+// See https://linebender.org/blog/doc-include for this README inclusion strategy
+// File links are not supported by rustdoc
+//!
+//! [LICENSE-APACHE]: https://github.com/robofinch/clone-behavior/blob/main/LICENSE-APACHE
+//! [LICENSE-MIT]: https://github.com/robofinch/clone-behavior/blob/main/LICENSE-MIT
+//!
+//! <style>
+//! .rustdoc-hidden { display: none; }
+//! </style>
+#![cfg_attr(doc, doc = include_str!("../README.md"))]
+
+#![cfg_attr(
+    test,
+    expect(unused_crate_dependencies, reason = "`clone-behavior` is only used by doctests"),
+)]
+
+mod attrs;
+mod expand;
+
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+use self::expand::{expand, CloneTrait};
+
+
+/// Derive `IndependentClone<S>` for every speed `S` at which every field can be independently
+/// cloned.
+///
+/// A single impl generic over the speed is emitted, with a `where` clause requiring each field's
+/// type to implement `IndependentClone<S>`. As a result, if every field implements
+/// `IndependentClone<NearInstant>` (and each slower speed, as is conventional), the derived type
+/// implements every speed from `NearInstant` through `AnySpeed`; if some field only implements
+/// `IndependentClone<AnySpeed>`, the derived type only implements `AnySpeed`.
+///
+/// # Attributes
+/// - `#[clone_behavior(speed = "Speed")]` on a field clones that field with its
+///   `IndependentClone<Speed>` impl, instead of with the speed of the derived impl. The derived
+///   type then only implements speeds which are `AtLeastAsSlowAs<Speed>`. This is useful for
+///   fields whose types implement a single speed, or to deliberately downgrade the derived speed.
+/// - `#[clone_behavior(speed = "Speed")]` on the type restricts the derived impl to speeds which
+///   are `AtLeastAsSlowAs<Speed>`.
+/// - `#[clone_behavior(bound = "T: Bound, ..")]` on a field replaces the bound which would be
+///   inferred from that field's type. An empty string adds no bounds.
+/// - `#[clone_behavior(bound = "T: Bound, ..")]` on the type replaces every bound which would be
+///   inferred from the fields.
+///
+/// In `bound` attributes, the type `_` refers to the speed of the derived impl; for instance,
+/// `bound = "T: IndependentClone<_>"`. The given speeds and bounds are resolved where the derive is
+/// used, like any other type.
+///
+/// `PhantomData` fields are recreated directly, without any bounds.
+///
+/// Unions are not supported.
+///
+/// # Examples
+/// Each speed supported by every field is supported by the derived type.
+///
+/// ```
+/// use clone_behavior::{AnySpeed, IndependentClone, LinearTime, NearInstant};
+///
+/// #[derive(IndependentClone, Debug, PartialEq)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// #[derive(IndependentClone, Debug, PartialEq)]
+/// struct Polygon {
+///     name:     String,
+///     vertices: Vec<Point>,
+/// }
+///
+/// let point = Point { x: 1, y: 2 };
+/// assert_eq!(IndependentClone::<NearInstant>::independent_clone(&point), point);
+/// assert_eq!(IndependentClone::<AnySpeed>::independent_clone(&point), point);
+///
+/// let polygon = Polygon { name: "triangle".to_owned(), vertices: vec![point] };
+/// assert_eq!(IndependentClone::<LinearTime>::independent_clone(&polygon), polygon);
+/// ```
+///
+/// Cloning a `Vec<u8>` is not near-instant, so neither is cloning a struct containing one.
+///
+/// ```compile_fail,E0277
+/// use clone_behavior::{IndependentClone, NearInstant};
+///
+/// #[derive(IndependentClone)]
+/// struct Message {
+///     bytes: Vec<u8>,
+/// }
+///
+/// let message = Message { bytes: vec![1, 2, 3] };
+/// let _ = IndependentClone::<NearInstant>::independent_clone(&message);
+/// ```
+///
+/// A `speed` attribute on a field clones it at that speed, which lets a field implementing a
+/// single speed be used in a type cloned at slower speeds. A `speed` attribute on the type caps
+/// the speed of the derived impl.
+///
+/// ```
+/// use clone_behavior::{AnySpeed, ConstantTime, IndependentClone, LinearTime};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Token(u64);
+///
+/// // Only `ConstantTime` is implemented, so the inferred bound would only allow `ConstantTime`.
+/// impl IndependentClone<ConstantTime> for Token {
+///     fn independent_clone(&self) -> Self {
+///         Self(self.0)
+///     }
+/// }
+///
+/// #[derive(IndependentClone, Debug, PartialEq)]
+/// struct Session {
+///     id:    u32,
+///     #[clone_behavior(speed = "ConstantTime")]
+///     token: Token,
+/// }
+///
+/// #[derive(IndependentClone, Debug, PartialEq)]
+/// #[clone_behavior(speed = "LinearTime")]
+/// struct Tag(u32);
+///
+/// let session = Session { id: 1, token: Token(2) };
+/// assert_eq!(IndependentClone::<AnySpeed>::independent_clone(&session), session);
+/// assert_eq!(IndependentClone::<LinearTime>::independent_clone(&Tag(3)), Tag(3));
+/// assert_eq!(IndependentClone::<AnySpeed>::independent_clone(&Tag(3)), Tag(3));
+/// ```
+///
+/// The capped type does not implement faster speeds.
+///
+/// ```compile_fail,E0277
+/// use clone_behavior::{ConstantTime, IndependentClone, LinearTime};
+///
+/// #[derive(IndependentClone)]
+/// #[clone_behavior(speed = "LinearTime")]
+/// struct Tag(u32);
+///
+/// let _ = IndependentClone::<ConstantTime>::independent_clone(&Tag(3));
+/// ```
+///
+/// A `bound` attribute replaces the inferred bounds, with `_` standing for the derived speed.
+/// `PhantomData` fields need no bounds at all, so `Meters` below does not need to be cloneable.
+///
+/// ```
+/// use core::marker::PhantomData;
+/// use clone_behavior::{AtLeastAsSlowAs, IndependentClone, LinearTime};
+///
+/// #[derive(IndependentClone)]
+/// #[clone_behavior(bound = "T: IndependentClone<_>, _: AtLeastAsSlowAs<LinearTime>")]
+/// struct Stack<T> {
+///     items: Vec<T>,
+/// }
+///
+/// struct Meters;
+///
+/// #[derive(IndependentClone)]
+/// struct Length<Unit> {
+///     value: f64,
+///     unit:  PhantomData<Unit>,
+/// }
+///
+/// let stack = Stack { items: vec![1_u8, 2, 3] };
+/// assert_eq!(IndependentClone::<LinearTime>::independent_clone(&stack).items, [1, 2, 3]);
+///
+/// let length = Length::<Meters> { value: 2.5, unit: PhantomData };
+/// assert_eq!(IndependentClone::<LinearTime>::independent_clone(&length).value, 2.5);
+/// ```
+///
+/// Unions are rejected.
+///
+/// ```compile_fail
+/// use clone_behavior::IndependentClone;
+///
+/// #[derive(IndependentClone)]
+/// union Bits {
+///     int:   u32,
+///     float: f32,
+/// }
+/// ```
+///
+/// # Recursive types
+/// The bound inferred for a field whose type contains the derived type, like an
+/// `Option<Box<Self>>` field, would require the derived type to implement the trait in order to
+/// implement the trait, which the compiler rejects. Such a field needs a `bound` attribute giving
+/// the bounds its clone needs other than that cycle. Note also that `Box<T>` only implements the
+/// cloning traits when `T` is given to `clone_behavior::impl_for_box!`, and only at speeds which
+/// are `AtLeastAsSlowAs<ConstantTime>`.
+///
+/// ```
+/// use clone_behavior::{impl_for_box, AnySpeed, AtLeastAsSlowAs, ConstantTime, IndependentClone};
+/// use clone_behavior::NonBlocking;
+///
+/// #[derive(IndependentClone, Debug, PartialEq)]
+/// #[clone_behavior(speed = "ConstantTime")]
+/// struct List {
+///     value: u32,
+///     // `Option<T>` is implemented at speeds at least as slow as `NonBlocking<ConstantTime>`.
+///     #[clone_behavior(bound = "_: AtLeastAsSlowAs<NonBlocking<ConstantTime>>")]
+///     next:  Option<Box<List>>,
+/// }
+///
+/// impl_for_box!(List);
+///
+/// let list = List { value: 1, next: Some(Box::new(List { value: 2, next: None })) };
+/// assert_eq!(IndependentClone::<AnySpeed>::independent_clone(&list), list);
+/// ```
+#[proc_macro_derive(IndependentClone, attributes(clone_behavior))]
+pub fn derive_independent_clone(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, CloneTrait::Independent)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `MirroredClone<S>` for every speed `S` at which every field can be mirrored-cloned.
+///
+/// A single impl generic over the speed is emitted, with a `where` clause requiring each field's
+/// type to implement `MirroredClone<S>`. The same attributes as for
+/// [`IndependentClone`](derive@IndependentClone) are supported; read its documentation for more.
+///
+/// Unions are not supported.
+///
+/// # Examples
+/// ```
+/// use std::{cell::Cell, rc::Rc};
+/// use clone_behavior::{MirroredClone, NearInstant};
+///
+/// #[derive(MirroredClone)]
+/// struct Detached;
+///
+/// #[derive(MirroredClone)]
+/// enum Counter {
+///     Shared(Rc<Cell<u32>>),
+///     Named { name: &'static str, count: Rc<Cell<u32>> },
+///     Detached(Detached),
+///     Disabled,
+/// }
+///
+/// let counter = Counter::Named { name: "hits", count: Rc::new(Cell::new(0)) };
+/// let mirror = MirroredClone::<NearInstant>::mirrored_clone(&counter);
+/// if let (Counter::Named { count, .. }, Counter::Named { count: mirrored, .. }) =
+///     (&counter, &mirror)
+/// {
+///     mirrored.set(1);
+///     assert_eq!(count.get(), 1);
+/// }
+///
+/// let disabled = MirroredClone::<NearInstant>::mirrored_clone(&Counter::Disabled);
+/// assert!(matches!(disabled, Counter::Disabled));
+/// ```
+#[proc_macro_derive(MirroredClone, attributes(clone_behavior))]
+pub fn derive_mirrored_clone(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, CloneTrait::Mirrored)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `MixedClone<S>` for every speed `S` at which every field can be mixed-cloned.
+///
+/// A single impl generic over the speed is emitted, with a `where` clause requiring each field's
+/// type to implement `MixedClone<S>`. The same attributes as for
+/// [`IndependentClone`](derive@IndependentClone) are supported; read its documentation for more.
+///
+/// Unions are not supported.
+#[proc_macro_derive(MixedClone, attributes(clone_behavior))]
+pub fn derive_mixed_clone(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, CloneTrait::Mixed)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
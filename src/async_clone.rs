@@ -0,0 +1,102 @@
+#![warn(clippy::missing_inline_in_public_items, reason = "almost everything is very short")]
+
+use crate::speed::Speed;
+#[cfg(feature = "tokio")]
+use tokio::sync::{Mutex as TokioMutex, RwLock as TokioRwLock};
+#[cfg(feature = "embassy-sync")]
+use embassy_sync::{blocking_mutex::raw::RawMutex, mutex::Mutex as EmbassyMutex};
+
+#[cfg(any(feature = "tokio", feature = "embassy-sync"))]
+use crate::{independent::IndependentClone, speed::{AtLeastAsSlowAs, ConstantTime}};
+
+
+/// Asynchronously get deep clones of a value, which do not share any semantically-important mutable
+/// state.
+///
+/// This is the asynchronous counterpart of [`IndependentClone`], for types whose independent clones
+/// require awaiting, such as values behind an asynchronous lock. The speed `S` describes the
+/// overhead of the clone itself, not including the time spent waiting for the future to be ready.
+///
+/// Types which implement [`IndependentClone`] can implement this trait with a future which is
+/// immediately ready, like [`core::future::ready`].
+///
+/// An independent clone returned by this trait must satisfy the same guarantees as one from
+/// [`IndependentClone`]; read its documentation for more.
+///
+/// [`IndependentClone`]: crate::IndependentClone
+pub trait AsyncIndependentClone<S: Speed>: Sized {
+    /// Asynchronously get a deep clone of a value, which does not share any
+    /// semantically-important mutable state.
+    ///
+    /// Read [`AsyncIndependentClone`] for more.
+    #[must_use]
+    fn async_independent_clone(&self) -> impl Future<Output = Self>;
+}
+
+/// Asynchronously get clones that share all semantically-important mutable state.
+///
+/// This is the asynchronous counterpart of [`MirroredClone`], for types whose mirrored clones
+/// require awaiting. The speed `S` describes the overhead of the clone itself, not including the
+/// time spent waiting for the future to be ready.
+///
+/// Types which implement [`MirroredClone`] can implement this trait with a future which is
+/// immediately ready, like [`core::future::ready`].
+///
+/// A mirrored clone returned by this trait must satisfy the same guarantees as one from
+/// [`MirroredClone`]; read its documentation for more.
+///
+/// [`MirroredClone`]: crate::MirroredClone
+pub trait AsyncMirroredClone<S: Speed>: Sized {
+    /// Asynchronously get a clone that shares all semantically-important mutable state with its
+    /// source.
+    ///
+    /// Read [`AsyncMirroredClone`] for more.
+    #[must_use]
+    fn async_mirrored_clone(&self) -> impl Future<Output = Self>;
+}
+
+
+#[cfg(feature = "tokio")]
+impl<S, T> AsyncIndependentClone<S> for TokioMutex<T>
+where
+    S: AtLeastAsSlowAs<ConstantTime>,
+    T: IndependentClone<S>,
+{
+    #[inline]
+    async fn async_independent_clone(&self) -> Self {
+        Self::new(T::independent_clone(&*self.lock().await))
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<S, T> AsyncIndependentClone<S> for TokioRwLock<T>
+where
+    S: AtLeastAsSlowAs<ConstantTime>,
+    T: IndependentClone<S>,
+{
+    #[expect(
+        clippy::future_not_send,
+        reason = "the future is `Send` whenever the `RwLock` is `Sync`",
+    )]
+    #[inline]
+    async fn async_independent_clone(&self) -> Self {
+        Self::new(T::independent_clone(&*self.read().await))
+    }
+}
+
+#[cfg(feature = "embassy-sync")]
+impl<S, M, T> AsyncIndependentClone<S> for EmbassyMutex<M, T>
+where
+    S: AtLeastAsSlowAs<ConstantTime>,
+    M: RawMutex,
+    T: IndependentClone<S>,
+{
+    #[expect(
+        clippy::future_not_send,
+        reason = "the future is `Send` whenever the `Mutex` is `Sync`",
+    )]
+    #[inline]
+    async fn async_independent_clone(&self) -> Self {
+        Self::new(T::independent_clone(&*self.lock().await))
+    }
+}
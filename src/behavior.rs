@@ -0,0 +1,63 @@
+use crate::{independent::IndependentClone, mirrored::MirroredClone, mixed::MixedClone};
+use crate::mode::{CloneMode, IndependentMode, MirroredMode, MixedMode};
+use crate::speed::Speed;
+
+
+/// Get clones whose semantic behavior is indicated by the [`CloneMode`] `M`, and whose overhead is
+/// indicated by the [`Speed`] `S`.
+///
+/// This trait is implemented for every type implementing [`IndependentClone`], [`MirroredClone`],
+/// or [`MixedClone`], with the corresponding mode. It should not be implemented directly; it exists
+/// so that generic code can be written once over the mode of cloning, and then be used with
+/// either independent, mirrored, or mixed clones.
+///
+/// A type implementing a cloning trait at some speed also implements it at every slower speed, as
+/// described for [`Speed`]. As such, a bound at the slowest acceptable speed also accepts every
+/// type with a faster clone; for instance, `T: CloneBehavior<IndependentMode, LogTime>` accepts
+/// types whose independent clones are [`NearInstant`], [`ConstantTime`], or [`LogTime`].
+///
+/// ```
+/// use clone_behavior::{CloneBehavior, IndependentMode, LogTime};
+///
+/// fn snapshot<T>(value: &T) -> T
+/// where
+///     T: CloneBehavior<IndependentMode, LogTime>,
+/// {
+///     value.clone_behavior()
+/// }
+///
+/// // `u32` has a `NearInstant` independent clone.
+/// assert_eq!(snapshot(&5_u32), 5);
+/// ```
+///
+/// [`NearInstant`]: crate::NearInstant
+/// [`ConstantTime`]: crate::ConstantTime
+/// [`LogTime`]: crate::LogTime
+pub trait CloneBehavior<M: CloneMode, S: Speed>: Sized {
+    /// Get a clone whose semantic behavior is indicated by `M`.
+    ///
+    /// Read [`CloneBehavior`] for more.
+    #[must_use]
+    fn clone_behavior(&self) -> Self;
+}
+
+impl<S: Speed, T: IndependentClone<S>> CloneBehavior<IndependentMode, S> for T {
+    #[inline]
+    fn clone_behavior(&self) -> Self {
+        self.independent_clone()
+    }
+}
+
+impl<S: Speed, T: MirroredClone<S>> CloneBehavior<MirroredMode, S> for T {
+    #[inline]
+    fn clone_behavior(&self) -> Self {
+        self.mirrored_clone()
+    }
+}
+
+impl<S: Speed, T: MixedClone<S>> CloneBehavior<MixedMode, S> for T {
+    #[inline]
+    fn clone_behavior(&self) -> Self {
+        self.mixed_clone()
+    }
+}
@@ -0,0 +1,201 @@
+//! Measure the latency of cloning operations across input sizes, to audit declared speeds.
+//!
+//! Each `measure_*` function constructs values of several sizes, times the corresponding cloning
+//! operation at each size, and estimates how the latency scales with the size. The resulting
+//! [`ScalingReport`] flags cloning operations whose observed scaling clearly contradicts the
+//! [`SpeedClass`] of their declared [`Speed`]; for instance, a supposedly `ConstantTime` clone
+//! whose latency grows linearly.
+//!
+//! ```
+//! use clone_behavior::LinearTime;
+//! use clone_behavior::bench::measure_independent;
+//!
+//! let report = measure_independent::<LinearTime, _>(|size| vec![0_u8; size], &[64, 1024], 8);
+//! assert_eq!(report.samples.len(), 2);
+//! ```
+//!
+//! These measurements are a heuristic: they are affected by noise, caching, and allocator
+//! behavior, and cannot distinguish constant time from logarithmic time. Sizes spanning several
+//! orders of magnitude give the most reliable results.
+
+#![expect(
+    clippy::impl_trait_in_params,
+    reason = "allows the speed to be given with a turbofish, without listing every generic",
+)]
+#![expect(clippy::float_arithmetic, reason = "estimating a scaling exponent")]
+
+use core::{hint::black_box, time::Duration};
+
+use alloc::vec::Vec;
+use std::time::Instant;
+
+use crate::{independent::IndependentClone, mirrored::MirroredClone, mixed::MixedClone};
+use crate::speed::{Speed, SpeedClass};
+
+
+/// The number of times that each batch of clones is timed; the fastest batch is used.
+const ROUNDS: usize = 5;
+
+/// The largest scaling exponent consistent with a logarithmic-time or faster speed.
+const MAX_SUBLINEAR_EXPONENT: f64 = 0.5;
+/// The largest scaling exponent consistent with a linear-time speed.
+const MAX_LINEAR_EXPONENT: f64 = 1.5;
+
+
+/// The observed latency of a cloning operation at each measured input size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScalingReport {
+    /// The [`SpeedClass`] of the declared speed of the cloning operation.
+    pub declared: SpeedClass,
+    /// Each input size, and the observed latency of a single clone at that size.
+    pub samples:  Vec<(usize, Duration)>,
+}
+
+impl ScalingReport {
+    /// Estimate `k` such that the latency of the cloning operation grows like `size^k`.
+    ///
+    /// This is the least-squares slope of the logarithm of the latency against the logarithm of
+    /// the size. Returns `None` if fewer than two distinct nonzero sizes were measured.
+    #[must_use]
+    pub fn exponent(&self) -> Option<f64> {
+        #[expect(
+            clippy::as_conversions,
+            clippy::cast_precision_loss,
+            reason = "precision loss is irrelevant to a heuristic",
+        )]
+        let points = self.samples
+            .iter()
+            .filter(|(size, _)| *size > 0)
+            .map(|(size, latency)| {
+                let latency = latency.as_secs_f64().max(f64::MIN_POSITIVE);
+                ((*size as f64).ln(), latency.ln())
+            })
+            .collect::<Vec<_>>();
+
+        #[expect(
+            clippy::as_conversions,
+            clippy::cast_precision_loss,
+            reason = "the number of samples is small",
+        )]
+        let len = points.len() as f64;
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / len;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / len;
+
+        let covariance = points.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum::<f64>();
+        let variance = points.iter().map(|(x, _)| (x - mean_x) * (x - mean_x)).sum::<f64>();
+
+        if variance > 0.0 {
+            Some(covariance / variance)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the observed scaling clearly contradicts the declared speed.
+    ///
+    /// A declared speed of `LogTime` or faster is contradicted by an [exponent] above `0.5`, and
+    /// `LinearTime` is contradicted by an exponent above `1.5`. `AnySpeed` is never contradicted.
+    ///
+    /// [exponent]: ScalingReport::exponent
+    #[must_use]
+    pub fn contradicts_declared_speed(&self) -> bool {
+        let Some(exponent) = self.exponent() else {
+            return false;
+        };
+
+        match self.declared {
+            SpeedClass::NearInstant | SpeedClass::ConstantTime | SpeedClass::LogTime => {
+                exponent > MAX_SUBLINEAR_EXPONENT
+            }
+            SpeedClass::LinearTime => exponent > MAX_LINEAR_EXPONENT,
+            SpeedClass::AnySpeed   => false,
+        }
+    }
+}
+
+/// Measure the latency of independent clones at speed `S` of values produced by `make` at each of
+/// the given sizes, timing batches of `iterations` clones.
+#[must_use]
+pub fn measure_independent<S, T>(
+    make:       impl Fn(usize) -> T,
+    sizes:      &[usize],
+    iterations: u32,
+) -> ScalingReport
+where
+    S: Speed,
+    T: IndependentClone<S>,
+{
+    measure::<S, T>(make, sizes, iterations, T::independent_clone)
+}
+
+/// Measure the latency of mirrored clones at speed `S` of values produced by `make` at each of the
+/// given sizes, timing batches of `iterations` clones.
+#[must_use]
+pub fn measure_mirrored<S, T>(
+    make:       impl Fn(usize) -> T,
+    sizes:      &[usize],
+    iterations: u32,
+) -> ScalingReport
+where
+    S: Speed,
+    T: MirroredClone<S>,
+{
+    measure::<S, T>(make, sizes, iterations, T::mirrored_clone)
+}
+
+/// Measure the latency of mixed clones at speed `S` of values produced by `make` at each of the
+/// given sizes, timing batches of `iterations` clones.
+#[must_use]
+pub fn measure_mixed<S, T>(
+    make:       impl Fn(usize) -> T,
+    sizes:      &[usize],
+    iterations: u32,
+) -> ScalingReport
+where
+    S: Speed,
+    T: MixedClone<S>,
+{
+    measure::<S, T>(make, sizes, iterations, T::mixed_clone)
+}
+
+fn measure<S: Speed, T>(
+    make:       impl Fn(usize) -> T,
+    sizes:      &[usize],
+    iterations: u32,
+    clone:      impl Fn(&T) -> T,
+) -> ScalingReport {
+    let iterations = iterations.max(1);
+
+    let samples = sizes
+        .iter()
+        .map(|&size| {
+            let value = make(size);
+            #[expect(
+                clippy::collection_is_never_read,
+                reason = "the clones are stored so that they are not dropped in the timed section",
+            )]
+            let mut clones = Vec::new();
+            let mut fastest = Duration::MAX;
+
+            for _ in 0..ROUNDS {
+                clones.reserve(usize::try_from(iterations).unwrap_or_default());
+
+                let start = Instant::now();
+                for _ in 0..iterations {
+                    clones.push(clone(black_box(&value)));
+                }
+                fastest = fastest.min(start.elapsed());
+
+                // Drop the clones outside of the timed section.
+                clones.clear();
+            }
+
+            (size, fastest / iterations)
+        })
+        .collect();
+
+    ScalingReport {
+        declared: S::CLASS,
+        samples,
+    }
+}
@@ -2,7 +2,8 @@
 
 use crate::call_varargs_macro;
 use crate::{independent::IndependentClone, mirrored::MirroredClone, mixed::MixedClone};
-use crate::speed::{NearInstant, ConstantTime, LogTime, AnySpeed};
+use crate::speed::{NearInstant, ConstantTime, LogTime, LinearTime, AnySpeed};
+use crate::speed::{Amortized, NonBlocking, CustomSpeed, SpeedTag};
 
 
 /// Indicates that the speed of cloning a type does not recursively depend on any generics, opting
@@ -11,38 +12,89 @@ use crate::speed::{NearInstant, ConstantTime, LogTime, AnySpeed};
 /// As examples, `u8` and `Rc<T>` implement this trait, while `Option<T>` does not.
 ///
 /// A `NonRecursive` type need only implement a cloning operation at the fastest applicable speed,
-/// and blanket implementations handle the rest. These blanket implementations would interfere
-/// with, for example, an attempt to pass through the speed of cloning `T` to the speed of cloning
-/// `Option<T>`.
+/// and blanket implementations handle the rest. The blanket implementations provide each speed from
+/// a single faster speed: `NonBlocking<ConstantTime>` from `NearInstant`, `ConstantTime` from
+/// `NonBlocking<ConstantTime>`, `NonBlocking<S>` and `Amortized<S>` from the next-fastest
+/// `NonBlocking` speed and from `S`, respectively, and each other speed from the next-fastest speed
+/// of [`ConstantTime`], [`LogTime`], [`LinearTime`], and [`AnySpeed`]. A type whose fastest speed
+/// is not `NearInstant`, `NonBlocking<ConstantTime>`, or `ConstantTime` may need to implement some
+/// speeds itself. Each [`CustomSpeed`] is provided from its baseline speed. These blanket
+/// implementations would interfere with, for example, an attempt to pass through the speed of
+/// cloning `T` to the speed of cloning `Option<T>`; such recursive types should instead implement a
+/// cloning operation at every speed `S` which is [`AtLeastAsSlowAs`] their fastest applicable
+/// speed, whenever their generics can be cloned at speed `S`.
+///
+/// [`AtLeastAsSlowAs`]: crate::AtLeastAsSlowAs
+/// [`ConstantTime`]: crate::ConstantTime
+/// [`LogTime`]: crate::LogTime
+/// [`LinearTime`]: crate::LinearTime
+/// [`AnySpeed`]: crate::AnySpeed
+/// [`CustomSpeed`]: crate::CustomSpeed
 pub trait NonRecursive {}
 
 
 macro_rules! blanket_impls {
-    ($clone_tr:ident, $clone_fn:ident) => {
-        impl<T: NonRecursive + $clone_tr<NearInstant>> $clone_tr<ConstantTime> for T {
-            #[inline]
-            fn $clone_fn(&self) -> Self {
-                <T as $clone_tr<NearInstant>>::$clone_fn(self)
-            }
-        }
+    ($clone_tr:ident, $clone_fn:ident $(, $clone_from_fn:ident)?) => {
+        blanket_impls! {
+            @impls $clone_tr, $clone_fn, [$($clone_from_fn)?];
+            NearInstant               => NonBlocking<ConstantTime>,
+            NonBlocking<ConstantTime> => ConstantTime,
+            ConstantTime              => LogTime,
+            LogTime                   => LinearTime,
+            LinearTime                => AnySpeed,
 
-        impl<T: NonRecursive + $clone_tr<ConstantTime>> $clone_tr<LogTime> for T {
-            #[inline]
-            fn $clone_fn(&self) -> Self {
-                <T as $clone_tr<ConstantTime>>::$clone_fn(self)
-            }
+            NonBlocking<ConstantTime> => NonBlocking<LogTime>,
+            NonBlocking<LogTime>      => NonBlocking<LinearTime>,
+            NonBlocking<LinearTime>   => NonBlocking<AnySpeed>,
+
+            ConstantTime              => Amortized<ConstantTime>,
+            LogTime                   => Amortized<LogTime>,
+            LinearTime                => Amortized<LinearTime>,
+        }
+    };
+    (
+        @impls $clone_tr:ident, $clone_fn:ident, $clone_from_fn:tt;
+        $($faster:ty => $slower:ty),* $(,)?
+    ) => {
+        blanket_impls! {
+            @impl {Tag, T} $clone_tr, $clone_fn, $clone_from_fn;
+            Tag::Baseline => CustomSpeed<Tag>;
+            Tag: SpeedTag,
         }
 
-        impl<T: NonRecursive + $clone_tr<LogTime>> $clone_tr<AnySpeed> for T {
+        $(
+            blanket_impls! {
+                @impl {T} $clone_tr, $clone_fn, $clone_from_fn;
+                $faster => $slower;
+            }
+        )*
+    };
+    (
+        @impl {$($params:ident),*} $clone_tr:ident, $clone_fn:ident, [$($clone_from_fn:ident)?];
+        $faster:ty => $slower:ty;
+        $($where_bounds:tt)*
+    ) => {
+        impl<$($params),*> $clone_tr<$slower> for T
+        where
+            T: NonRecursive + $clone_tr<$faster>,
+            $($where_bounds)*
+        {
             #[inline]
             fn $clone_fn(&self) -> Self {
-                <T as $clone_tr<LogTime>>::$clone_fn(self)
+                <T as $clone_tr<$faster>>::$clone_fn(self)
             }
+
+            $(
+                #[inline]
+                fn $clone_from_fn(&mut self, source: &Self) {
+                    <T as $clone_tr<$faster>>::$clone_from_fn(self, source);
+                }
+            )?
         }
     };
 }
 
-blanket_impls!(IndependentClone, independent_clone);
+blanket_impls!(IndependentClone, independent_clone, independent_clone_from);
 blanket_impls!(MirroredClone, mirrored_clone);
 blanket_impls!(MixedClone, mixed_clone);
 
@@ -76,31 +128,121 @@ non_recursive! {
     {for T: ?Sized} *mut T,
     core::alloc::Layout,
     core::any::TypeId,
+    {for T, const N: usize} core::array::IntoIter<T, N>,
     core::cmp::Ordering,
     core::convert::Infallible,
+    {for H} core::hash::BuildHasherDefault<H>,
+    {for A, B} core::iter::Chain<A, B>,
     {for T} core::iter::Empty<T>,
+    {for I} core::iter::Enumerate<I>,
+    {for T} core::iter::Once<T>,
+    {for T} core::iter::Repeat<T>,
+    {for I} core::iter::Rev<I>,
+    {for I} core::iter::Skip<I>,
+    {for I} core::iter::Take<I>,
     {for T: ?Sized} core::marker::PhantomData<T>,
     core::marker::PhantomPinned,
     {for T} core::mem::Discriminant<T>,
+    {for T: Copy} core::mem::MaybeUninit<T>,
+    core::net::IpAddr,
+    core::net::Ipv4Addr,
+    core::net::Ipv6Addr,
+    core::net::SocketAddr,
+    core::net::SocketAddrV4,
+    core::net::SocketAddrV6,
     core::ops::RangeFull,
     {for T: ?Sized} core::ptr::NonNull<T>,
+    {for T} core::slice::Iter<'_, T>,
+    core::str::Bytes<'_>,
+    core::str::CharIndices<'_>,
+    core::str::Chars<'_>,
     core::sync::atomic::Ordering,
+    core::task::Waker,
     core::time::Duration,
 }
 
 #[cfg(feature = "alloc")]
 non_recursive! {
+    alloc::ffi::CString,
     alloc::string::String,
 }
 
 #[cfg(feature = "std")]
 non_recursive! {
+    std::ffi::OsString,
+    std::hash::DefaultHasher,
+    std::hash::RandomState,
+    std::io::SeekFrom,
+    std::io::Stderr,
+    std::io::Stdin,
+    std::io::Stdout,
     std::path::Path,
     std::path::PathBuf,
+    {for T} std::sync::mpsc::Sender<T>,
+    {for T} std::sync::mpsc::SyncSender<T>,
     std::time::Instant,
+    std::time::SystemTime,
     std::thread::ThreadId,
 }
 
+#[cfg(feature = "std")]
+#[allow(
+    clippy::std_instead_of_core,
+    reason = "`core::io` is unstable, and only nightly Clippy suggests it",
+)]
+const _: () = {
+    non_recursive! {
+        std::io::Empty,
+        std::io::Sink,
+    }
+};
+
+// Error types
+non_recursive! {
+    core::alloc::LayoutError,
+    core::array::TryFromSliceError,
+    core::char::CharTryFromError,
+    core::char::ParseCharError,
+    core::char::TryFromCharError,
+    core::fmt::Error,
+    core::num::ParseFloatError,
+    core::num::ParseIntError,
+    core::num::TryFromIntError,
+    core::str::ParseBoolError,
+    core::str::Utf8Error,
+}
+
+#[cfg(feature = "alloc")]
+non_recursive! {
+    alloc::collections::TryReserveError,
+}
+
+#[cfg(feature = "allocator_api")]
+non_recursive! {
+    core::alloc::AllocError,
+}
+
+#[cfg(feature = "std")]
+#[allow(
+    clippy::std_instead_of_core,
+    reason = "`core::io` is unstable, and only nightly Clippy suggests it",
+)]
+const _: () = {
+    non_recursive! {
+        std::io::ErrorKind,
+    }
+};
+
+#[cfg(feature = "std")]
+non_recursive! {
+    std::path::StripPrefixError,
+    std::sync::mpsc::RecvError,
+    std::sync::mpsc::RecvTimeoutError,
+    std::sync::mpsc::TryRecvError,
+    std::thread::AccessError,
+    std::time::SystemTimeError,
+}
+
 macro_rules! atomic {
     ($($name:ident $bits:literal),* $(,)?) => {
         $(
@@ -122,6 +264,214 @@ atomic! {
 #[cfg(target_has_atomic = "ptr")]
 impl<T> NonRecursive for core::sync::atomic::AtomicPtr<T> {}
 
+#[cfg(feature = "arcstr")]
+non_recursive! {
+    arcstr::ArcStr,
+}
+
+#[cfg(feature = "async-channel")]
+non_recursive! {
+    {for T} async_channel::Sender<T>,
+    {for T} async_channel::Receiver<T>,
+}
+
+#[cfg(feature = "bigdecimal")]
+non_recursive! {
+    bigdecimal::BigDecimal,
+}
+
+#[cfg(feature = "bytes")]
+non_recursive! {
+    bytes::Bytes,
+    bytes::BytesMut,
+}
+
+#[cfg(feature = "camino")]
+non_recursive! {
+    camino::Utf8Path,
+    camino::Utf8PathBuf,
+}
+
+#[cfg(feature = "compact_str")]
+non_recursive! {
+    compact_str::CompactString,
+}
+
+#[cfg(feature = "crossbeam-channel")]
+non_recursive! {
+    {for T} crossbeam_channel::Sender<T>,
+    {for T} crossbeam_channel::Receiver<T>,
+}
+
+#[cfg(feature = "ecow")]
+non_recursive! {
+    ecow::EcoString,
+}
+
+#[cfg(feature = "evmap")]
+non_recursive! {
+    {
+        for K: Eq + core::hash::Hash, V, M, S: core::hash::BuildHasher
+    } evmap::ReadHandle<K, V, M, S>,
+    {
+        for K: Eq + core::hash::Hash, V, M, S: core::hash::BuildHasher
+    } evmap::ReadHandleFactory<K, V, M, S>,
+}
+
+#[cfg(feature = "flume")]
+non_recursive! {
+    {for T} flume::Sender<T>,
+    {for T} flume::Receiver<T>,
+}
+
+#[cfg(feature = "futures")]
+non_recursive! {
+    {for T} futures::channel::mpsc::Sender<T>,
+    {for T} futures::channel::mpsc::UnboundedSender<T>,
+    {for Fut: Future} futures::future::Shared<Fut>,
+}
+
+#[cfg(feature = "hashbrown")]
+non_recursive! {
+    hashbrown::DefaultHashBuilder,
+}
+
+#[cfg(feature = "heapless")]
+non_recursive! {
+    {for LenT: heapless::LenType, const N: usize} heapless::String<N, LenT>,
+}
+
+#[cfg(feature = "im")]
+non_recursive! {
+    {for A} im::Vector<A>,
+    {for A, S} im::HashSet<A, S>,
+    {for K, V, S} im::HashMap<K, V, S>,
+    {for A} im::OrdSet<A>,
+    {for K, V} im::OrdMap<K, V>,
+}
+
+#[cfg(feature = "imbl")]
+non_recursive! {
+    {for A, P: imbl::shared_ptr::SharedPointerKind} imbl::GenericVector<A, P>,
+    {for A, S, P: imbl::shared_ptr::SharedPointerKind} imbl::GenericHashSet<A, S, P>,
+    {for K, V, S, P: imbl::shared_ptr::SharedPointerKind} imbl::GenericHashMap<K, V, S, P>,
+    {for A, P: imbl::shared_ptr::SharedPointerKind} imbl::GenericOrdSet<A, P>,
+    {for K, V, P: imbl::shared_ptr::SharedPointerKind} imbl::GenericOrdMap<K, V, P>,
+}
+
+#[cfg(feature = "jiff")]
+non_recursive! {
+    jiff::Timestamp,
+    jiff::Span,
+    jiff::civil::Date,
+    jiff::civil::Time,
+    jiff::civil::DateTime,
+    jiff::civil::ISOWeekDate,
+    jiff::civil::Weekday,
+    jiff::Zoned,
+}
+
+#[cfg(feature = "left-right")]
+non_recursive! {
+    {for T} left_right::ReadHandle<T>,
+    {for T} left_right::ReadHandleFactory<T>,
+}
+
+#[cfg(feature = "num")]
+non_recursive! {
+    num_bigint::BigInt,
+    num_bigint::BigUint,
+}
+
+#[cfg(feature = "portable-atomic")]
+non_recursive! {
+    portable_atomic::AtomicBool,
+    portable_atomic::AtomicI8,    portable_atomic::AtomicU8,
+    portable_atomic::AtomicI16,   portable_atomic::AtomicU16,
+    portable_atomic::AtomicI32,   portable_atomic::AtomicU32,
+    portable_atomic::AtomicI64,   portable_atomic::AtomicU64,
+    portable_atomic::AtomicI128,  portable_atomic::AtomicU128,
+    portable_atomic::AtomicIsize, portable_atomic::AtomicUsize,
+    portable_atomic::AtomicF32,   portable_atomic::AtomicF64,
+    {for T} portable_atomic::AtomicPtr<T>,
+}
+
+#[cfg(feature = "rpds")]
+non_recursive! {
+    {for T, P: archery::SharedPointerKind} rpds::Vector<T, P>,
+    {for T, P: archery::SharedPointerKind} rpds::List<T, P>,
+    {for T, P: archery::SharedPointerKind} rpds::Stack<T, P>,
+    {for T, P: archery::SharedPointerKind} rpds::Queue<T, P>,
+    {
+        for T: Eq + core::hash::Hash, P: archery::SharedPointerKind,
+        H: core::hash::BuildHasher + Clone
+    } rpds::HashTrieSet<T, P, H>,
+    {
+        for K, V, P: archery::SharedPointerKind, H: core::hash::BuildHasher
+    } rpds::HashTrieMap<K, V, P, H>,
+    {for T: Ord, P: archery::SharedPointerKind} rpds::RedBlackTreeSet<T, P>,
+    {for K, V, P: archery::SharedPointerKind} rpds::RedBlackTreeMap<K, V, P>,
+}
+
+#[cfg(feature = "rust_decimal")]
+non_recursive! {
+    rust_decimal::Decimal,
+}
+
+#[cfg(feature = "smol_str")]
+non_recursive! {
+    smol_str::SmolStr,
+}
+
+#[cfg(feature = "tokio")]
+non_recursive! {
+    {for T} tokio::sync::broadcast::Receiver<T>,
+    {for T} tokio::sync::broadcast::Sender<T>,
+    {for T} tokio::sync::mpsc::Sender<T>,
+    {for T} tokio::sync::mpsc::UnboundedSender<T>,
+    {for T} tokio::sync::mpsc::WeakSender<T>,
+    {for T} tokio::sync::mpsc::WeakUnboundedSender<T>,
+    {for T} tokio::sync::watch::Receiver<T>,
+    {for T} tokio::sync::watch::Sender<T>,
+}
+
+#[cfg(feature = "tokio-util")]
+non_recursive! {
+    tokio_util::sync::CancellationToken,
+}
+
+#[cfg(feature = "chrono")]
+non_recursive! {
+    chrono::NaiveDate,
+    chrono::NaiveTime,
+    chrono::NaiveDateTime,
+    chrono::DateTime<chrono::Utc>,
+    chrono::DateTime<chrono::FixedOffset>,
+    chrono::Utc,
+    chrono::FixedOffset,
+    chrono::TimeDelta,
+}
+
+#[cfg(feature = "time")]
+non_recursive! {
+    time::Date,
+    time::Time,
+    time::OffsetDateTime,
+    time::PrimitiveDateTime,
+    time::Duration,
+    time::UtcOffset,
+}
+
+#[cfg(feature = "ulid")]
+non_recursive! {
+    ulid::Ulid,
+}
+
+#[cfg(feature = "uuid")]
+non_recursive! {
+    uuid::Uuid,
+}
+
 macro_rules! function {
     ($($args:ident),*) => {
         impl<R, $($args),*> NonRecursive for fn($($args),*) -> R {}
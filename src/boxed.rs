@@ -0,0 +1,449 @@
+#![expect(unsafe_code, reason = "replace the data pointer of a possibly-unsized pointer")]
+#![warn(clippy::missing_inline_in_public_items, reason = "almost everything is very short")]
+
+mod private {
+    /// Prevents the methods of the object-safe traits from being called or overridden outside
+    /// this crate.
+    #[expect(unnameable_types, reason = "This is intentional, and seals the trait methods")]
+    #[derive(Debug, Clone, Copy)]
+    pub struct Private;
+}
+
+
+use core::ptr;
+
+use alloc::boxed::Box;
+
+use crate::{independent::IndependentClone, mirrored::MirroredClone};
+use crate::speed::{Speed, AtLeastAsSlowAs, ConstantTime};
+
+use self::private::Private;
+
+
+/// Object-safe counterpart of [`IndependentClone`], which allows `dyn Trait` values to be
+/// independently cloned into a new [`Box`].
+///
+/// This trait is implemented for every type implementing [`IndependentClone<S>`] at a speed `S`
+/// which is [`AtLeastAsSlowAs`] [`ConstantTime`] (since the clone is placed in a new allocation).
+/// By making it a supertrait of your trait, a `Box<dyn YourTrait>` can be cloned with
+/// [`independent_clone_box`], or, with [`independent_clone_trait_object!`], with
+/// [`IndependentClone`].
+///
+/// [`independent_clone_trait_object!`]: crate::independent_clone_trait_object
+///
+/// ```
+/// use clone_behavior::{independent_clone_trait_object, IndependentClone, IndependentCloneBoxed};
+/// use clone_behavior::LinearTime;
+///
+/// trait Shape: IndependentCloneBoxed<LinearTime> {
+///     fn area(&self) -> f64;
+/// }
+///
+/// independent_clone_trait_object!(Shape);
+///
+/// fn duplicate(shape: &Box<dyn Shape>) -> Box<dyn Shape> {
+///     IndependentClone::<LinearTime>::independent_clone(shape)
+/// }
+/// ```
+pub trait IndependentCloneBoxed<S: Speed> {
+    /// Get a pointer to a new box containing an independent clone of `self`.
+    #[doc(hidden)]
+    fn __independent_clone_boxed(&self, _: Private) -> *mut ();
+}
+
+/// Object-safe counterpart of [`MirroredClone`], which allows `dyn Trait` values to be
+/// mirrored-cloned into a new [`Box`].
+///
+/// This trait is implemented for every type implementing [`MirroredClone<S>`] at a speed `S`
+/// which is [`AtLeastAsSlowAs`] [`ConstantTime`] (since the clone is placed in a new allocation).
+/// By making it a supertrait of your trait, a `Box<dyn YourTrait>` can be cloned with
+/// [`mirrored_clone_box`], or, with [`mirrored_clone_trait_object!`], with [`MirroredClone`].
+/// Read [`IndependentCloneBoxed`] for an example.
+///
+/// [`mirrored_clone_trait_object!`]: crate::mirrored_clone_trait_object
+pub trait MirroredCloneBoxed<S: Speed> {
+    /// Get a pointer to a new box containing a mirrored clone of `self`.
+    #[doc(hidden)]
+    fn __mirrored_clone_boxed(&self, _: Private) -> *mut ();
+}
+
+impl<S, T> IndependentCloneBoxed<S> for T
+where
+    S: AtLeastAsSlowAs<ConstantTime>,
+    T: IndependentClone<S>,
+{
+    #[inline]
+    fn __independent_clone_boxed(&self, _: Private) -> *mut () {
+        Box::into_raw(Box::new(self.independent_clone())).cast()
+    }
+}
+
+impl<S, T> MirroredCloneBoxed<S> for T
+where
+    S: AtLeastAsSlowAs<ConstantTime>,
+    T: MirroredClone<S>,
+{
+    #[inline]
+    fn __mirrored_clone_boxed(&self, _: Private) -> *mut () {
+        Box::into_raw(Box::new(self.mirrored_clone())).cast()
+    }
+}
+
+/// Get an independent clone of a possibly-unsized value, such as a `dyn Trait`, in a new [`Box`].
+///
+/// Read [`IndependentCloneBoxed`] for more.
+#[inline]
+#[must_use]
+pub fn independent_clone_box<S, T>(value: &T) -> Box<T>
+where
+    S: Speed,
+    T: ?Sized + IndependentCloneBoxed<S>,
+{
+    let new_data = value.__independent_clone_boxed(Private);
+    // SAFETY: `__independent_clone_boxed` is only implemented by this crate, for sized types,
+    // and returns a pointer from `Box::<T>::into_raw` cast to `*mut ()`.
+    unsafe { box_from_raw_data(value, new_data) }
+}
+
+/// Get a mirrored clone of a possibly-unsized value, such as a `dyn Trait`, in a new [`Box`].
+///
+/// Read [`MirroredCloneBoxed`] for more.
+#[inline]
+#[must_use]
+pub fn mirrored_clone_box<S, T>(value: &T) -> Box<T>
+where
+    S: Speed,
+    T: ?Sized + MirroredCloneBoxed<S>,
+{
+    let new_data = value.__mirrored_clone_boxed(Private);
+    // SAFETY: `__mirrored_clone_boxed` is only implemented by this crate, for sized types,
+    // and returns a pointer from `Box::<T>::into_raw` cast to `*mut ()`.
+    unsafe { box_from_raw_data(value, new_data) }
+}
+
+/// Construct a `Box<T>` whose data pointer is `new_data`, and whose pointer metadata (such as a
+/// vtable) is that of `value`.
+///
+/// # Safety
+/// `new_data` must have been returned by `Box::into_raw` for a box of the concrete type of `value`,
+/// cast to `*mut ()`.
+unsafe fn box_from_raw_data<T>(value: &T, new_data: *mut ()) -> Box<T>
+where
+    T: ?Sized,
+{
+    let mut fat_ptr = ptr::from_ref(value).cast_mut();
+    let data_ptr = (&raw mut fat_ptr).cast::<*mut ()>();
+
+    // SAFETY: `data_ptr` points to the start of `fat_ptr`, which is a properly-aligned pointer
+    // that is at least as large as a thin pointer.
+    let old_data = unsafe { data_ptr.replace(new_data) };
+    // A pointer to a (possibly unsized) type is not guaranteed by the language to begin with its
+    // data pointer, so this is checked.
+    assert_eq!(
+        old_data,
+        ptr::from_ref(value).cast_mut().cast::<()>(),
+        "the data pointer of a pointer should be at its start",
+    );
+
+    // SAFETY: `fat_ptr` now has the data pointer of a box of the concrete type of `value`, and
+    // the metadata of `value`, so it is a pointer from `Box::<T>::into_raw`.
+    unsafe { Box::from_raw(fat_ptr) }
+}
+
+/// Implement [`IndependentClone`] for `Box<dyn Trait>`, where `Trait` has [`IndependentCloneBoxed`]
+/// as a supertrait.
+///
+/// The implementations are provided for `dyn Trait`, `dyn Trait + Send`, `dyn Trait + Sync`, and
+/// `dyn Trait + Send + Sync`, at each speed `S` such that `dyn Trait: IndependentCloneBoxed<S>`.
+/// Read [`IndependentCloneBoxed`] for an example.
+#[macro_export]
+macro_rules! independent_clone_trait_object {
+    ($trait:path) => {
+        $crate::__trait_object_impls! {
+            $trait, IndependentClone, independent_clone, IndependentCloneBoxed,
+            independent_clone_box
+        }
+    };
+}
+
+/// Implement [`MirroredClone`] for `Box<dyn Trait>`, where `Trait` has [`MirroredCloneBoxed`] as
+/// a supertrait.
+///
+/// The implementations are provided for `dyn Trait`, `dyn Trait + Send`, `dyn Trait + Sync`, and
+/// `dyn Trait + Send + Sync`, at each speed `S` such that `dyn Trait: MirroredCloneBoxed<S>`.
+#[macro_export]
+macro_rules! mirrored_clone_trait_object {
+    ($trait:path) => {
+        $crate::__trait_object_impls! {
+            $trait, MirroredClone, mirrored_clone, MirroredCloneBoxed, mirrored_clone_box
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __trait_object_impls {
+    ($trait:path, $clone_tr:ident, $clone_fn:ident, $boxed_tr:ident, $box_fn:ident) => {
+        $crate::__trait_object_impls!(
+            @impl $clone_tr, $clone_fn, $boxed_tr, $box_fn, dyn $trait
+        );
+        $crate::__trait_object_impls!(
+            @impl $clone_tr, $clone_fn, $boxed_tr, $box_fn, dyn $trait + ::core::marker::Send
+        );
+        $crate::__trait_object_impls!(
+            @impl $clone_tr, $clone_fn, $boxed_tr, $box_fn, dyn $trait + ::core::marker::Sync
+        );
+        $crate::__trait_object_impls!(
+            @impl $clone_tr, $clone_fn, $boxed_tr, $box_fn,
+            dyn $trait + ::core::marker::Send + ::core::marker::Sync
+        );
+    };
+    (@dyn_clone $trait:path, $speed:ty, $clone_tr:ident, $clone_fn:ident) => {
+        $crate::__trait_object_impls!(@dyn_clone_impl $speed, $clone_tr, $clone_fn, dyn $trait);
+        $crate::__trait_object_impls!(
+            @dyn_clone_impl $speed, $clone_tr, $clone_fn, dyn $trait + ::core::marker::Send
+        );
+        $crate::__trait_object_impls!(
+            @dyn_clone_impl $speed, $clone_tr, $clone_fn, dyn $trait + ::core::marker::Sync
+        );
+        $crate::__trait_object_impls!(
+            @dyn_clone_impl $speed, $clone_tr, $clone_fn,
+            dyn $trait + ::core::marker::Send + ::core::marker::Sync
+        );
+    };
+    (@dyn_clone_impl $speed:ty, $clone_tr:ident, $clone_fn:ident, $dyn:ty) => {
+        impl<S> $crate::$clone_tr<S> for $crate::__private::Box<$dyn>
+        where
+            S: $crate::AtLeastAsSlowAs<$speed>,
+        {
+            #[inline]
+            fn $clone_fn(&self) -> Self {
+                $crate::__private::dyn_clone::clone_box(&**self)
+            }
+        }
+    };
+    (@impl $clone_tr:ident, $clone_fn:ident, $boxed_tr:ident, $box_fn:ident, $dyn:ty) => {
+        impl<S> $crate::$clone_tr<S> for $crate::__private::Box<$dyn>
+        where
+            S:    $crate::Speed,
+            $dyn: $crate::$boxed_tr<S>,
+        {
+            #[inline]
+            fn $clone_fn(&self) -> Self {
+                $crate::$box_fn::<S, $dyn>(&**self)
+            }
+        }
+    };
+}
+
+/// Implement [`IndependentClone`], [`MirroredClone`], and [`MixedClone`] for `Box<Type>` and
+/// `Pin<Box<Type>>`, for the given sized types.
+///
+/// The implementations of each trait are provided at each speed `S` which is [`AtLeastAsSlowAs`]
+/// [`ConstantTime`] and at which `Type` implements the trait, and clone the contents into a new
+/// box. A mirrored (or mixed) clone of the box holds a mirrored (or mixed) clone of the contents,
+/// so it shares the same semantically-important mutable state with its source as the contents do.
+///
+/// This crate cannot provide these implementations generically over `Box<T>`, for the coherence
+/// reasons described for [`IndependentClone`](crate::IndependentClone#boxt), but since `Box` is a
+/// fundamental type, a crate may implement them for its own types. This is what allows boxed
+/// recursive data structures to be cloned.
+///
+/// Generic types can be given with their generic parameters in a leading `{for ..}` block, as in
+/// `impl_for_box!({for T} Tree<T>)`.
+///
+/// ```
+/// use clone_behavior::{impl_for_box, AtLeastAsSlowAs, IndependentClone};
+/// use clone_behavior::{AnySpeed, ConstantTime, NonBlocking};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct List {
+///     value: u32,
+///     next:  Option<Box<List>>,
+/// }
+///
+/// impl<S> IndependentClone<S> for List
+/// where
+///     S: AtLeastAsSlowAs<ConstantTime> + AtLeastAsSlowAs<NonBlocking<ConstantTime>>,
+///     u32: IndependentClone<S>,
+/// {
+///     fn independent_clone(&self) -> Self {
+///         Self {
+///             value: self.value.independent_clone(),
+///             next:  IndependentClone::<S>::independent_clone(&self.next),
+///         }
+///     }
+/// }
+///
+/// impl_for_box!(List);
+///
+/// let list = List { value: 1, next: Some(Box::new(List { value: 2, next: None })) };
+/// assert_eq!(IndependentClone::<AnySpeed>::independent_clone(&list), list);
+/// ```
+///
+/// ```
+/// use std::{cell::Cell, rc::Rc};
+/// use clone_behavior::{impl_for_box, clone_is_mirrored, ConstantTime, MirroredClone};
+///
+/// #[derive(Clone)]
+/// struct Counter(Rc<Cell<u32>>);
+///
+/// clone_is_mirrored!(Counter, ConstantTime);
+/// impl_for_box!(Counter);
+///
+/// let counter = Box::new(Counter(Rc::new(Cell::new(0))));
+/// let mirror = MirroredClone::<ConstantTime>::mirrored_clone(&counter);
+/// mirror.0.set(1);
+/// assert_eq!(counter.0.get(), 1);
+/// ```
+///
+/// [`MixedClone`]: crate::MixedClone
+#[macro_export]
+macro_rules! impl_for_box {
+    ($($({for $($bounds:tt)+})? $type:ty),* $(,)?) => {
+        $(
+            impl<$($($bounds)+,)? __S> $crate::IndependentClone<__S>
+            for $crate::__private::Box<$type>
+            where
+                __S:   $crate::AtLeastAsSlowAs<$crate::ConstantTime>,
+                $type: $crate::IndependentClone<__S>,
+            {
+                #[inline]
+                fn independent_clone(&self) -> Self {
+                    $crate::__private::Box::new(
+                        <$type as $crate::IndependentClone<__S>>::independent_clone(self),
+                    )
+                }
+
+                #[inline]
+                fn independent_clone_from(&mut self, source: &Self) {
+                    <$type as $crate::IndependentClone<__S>>::independent_clone_from(
+                        self,
+                        source,
+                    );
+                }
+            }
+
+            impl<$($($bounds)+,)? __S> $crate::IndependentClone<__S>
+            for ::core::pin::Pin<$crate::__private::Box<$type>>
+            where
+                __S:   $crate::AtLeastAsSlowAs<$crate::ConstantTime>,
+                $type: $crate::IndependentClone<__S>,
+            {
+                #[inline]
+                fn independent_clone(&self) -> Self {
+                    $crate::__private::Box::pin(
+                        <$type as $crate::IndependentClone<__S>>::independent_clone(self),
+                    )
+                }
+            }
+
+            impl<$($($bounds)+,)? __S> $crate::MirroredClone<__S>
+            for $crate::__private::Box<$type>
+            where
+                __S:   $crate::AtLeastAsSlowAs<$crate::ConstantTime>,
+                $type: $crate::MirroredClone<__S>,
+            {
+                #[inline]
+                fn mirrored_clone(&self) -> Self {
+                    $crate::__private::Box::new(
+                        <$type as $crate::MirroredClone<__S>>::mirrored_clone(self),
+                    )
+                }
+            }
+
+            impl<$($($bounds)+,)? __S> $crate::MirroredClone<__S>
+            for ::core::pin::Pin<$crate::__private::Box<$type>>
+            where
+                __S:   $crate::AtLeastAsSlowAs<$crate::ConstantTime>,
+                $type: $crate::MirroredClone<__S>,
+            {
+                #[inline]
+                fn mirrored_clone(&self) -> Self {
+                    $crate::__private::Box::pin(
+                        <$type as $crate::MirroredClone<__S>>::mirrored_clone(self),
+                    )
+                }
+            }
+
+            impl<$($($bounds)+,)? __S> $crate::MixedClone<__S>
+            for $crate::__private::Box<$type>
+            where
+                __S:   $crate::AtLeastAsSlowAs<$crate::ConstantTime>,
+                $type: $crate::MixedClone<__S>,
+            {
+                #[inline]
+                fn mixed_clone(&self) -> Self {
+                    $crate::__private::Box::new(
+                        <$type as $crate::MixedClone<__S>>::mixed_clone(self),
+                    )
+                }
+            }
+
+            impl<$($($bounds)+,)? __S> $crate::MixedClone<__S>
+            for ::core::pin::Pin<$crate::__private::Box<$type>>
+            where
+                __S:   $crate::AtLeastAsSlowAs<$crate::ConstantTime>,
+                $type: $crate::MixedClone<__S>,
+            {
+                #[inline]
+                fn mixed_clone(&self) -> Self {
+                    $crate::__private::Box::pin(
+                        <$type as $crate::MixedClone<__S>>::mixed_clone(self),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+/// Implement [`IndependentClone`] for `Box<dyn Trait>`, where `Trait` has [`dyn_clone::DynClone`]
+/// as a supertrait.
+///
+/// The [`Clone`] implementation of every implementor of `Trait` must be an independent clone at the
+/// given speed. This bridges traits written for the [`dyn_clone`] crate, in the same way as
+/// [`dyn_clone::clone_trait_object!`]. The implementations are provided for `dyn Trait`,
+/// `dyn Trait + Send`, `dyn Trait + Sync`, and `dyn Trait + Send + Sync`, at each speed which is
+/// [`AtLeastAsSlowAs`] the given speed. Since the clone is placed in a new allocation, the given
+/// speed should be at least as slow as [`ConstantTime`].
+///
+/// ```
+/// use clone_behavior::{independent_dyn_clone_trait_object, IndependentClone, LinearTime};
+/// use dyn_clone::DynClone;
+///
+/// trait Shape: DynClone {
+///     fn area(&self) -> f64;
+/// }
+///
+/// independent_dyn_clone_trait_object!(Shape, LinearTime);
+///
+/// fn duplicate(shape: &Box<dyn Shape>) -> Box<dyn Shape> {
+///     IndependentClone::<LinearTime>::independent_clone(shape)
+/// }
+/// ```
+#[cfg(feature = "dyn-clone")]
+#[macro_export]
+macro_rules! independent_dyn_clone_trait_object {
+    ($trait:path, $speed:ty $(,)?) => {
+        $crate::__trait_object_impls! {
+            @dyn_clone $trait, $speed, IndependentClone, independent_clone
+        }
+    };
+}
+
+/// Implement [`MirroredClone`] for `Box<dyn Trait>`, where `Trait` has [`dyn_clone::DynClone`] as
+/// a supertrait.
+///
+/// The [`Clone`] implementation of every implementor of `Trait` must be a mirrored clone at the
+/// given speed. Read [`independent_dyn_clone_trait_object!`] for more.
+///
+/// [`independent_dyn_clone_trait_object!`]: crate::independent_dyn_clone_trait_object
+#[cfg(feature = "dyn-clone")]
+#[macro_export]
+macro_rules! mirrored_dyn_clone_trait_object {
+    ($trait:path, $speed:ty $(,)?) => {
+        $crate::__trait_object_impls! {
+            @dyn_clone $trait, $speed, MirroredClone, mirrored_clone
+        }
+    };
+}
@@ -0,0 +1,112 @@
+#![warn(clippy::missing_inline_in_public_items, reason = "almost everything is very short")]
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use bumpalo::Bump;
+use bumpalo::collections::{String as BumpString, Vec as BumpVec};
+
+use crate::independent::IndependentClone;
+use crate::speed::{Speed, AtLeastAsSlowAs, LinearTime};
+
+
+/// Get deep clones of a value, which do not share any semantically-important mutable state, and
+/// whose allocations are made in a [`bumpalo`] arena.
+///
+/// This is the arena-targeted counterpart of [`IndependentClone`], and works on stable Rust
+/// (unlike `IndependentCloneIn`, which requires the nightly `allocator_api` feature). The clone
+/// is of type [`Output`](Self::Output), which is usually a `bumpalo` collection or a reference
+/// into the arena. Only the allocations made by the value itself are made in the arena; for
+/// instance, the elements of a `Vec<Vec<T>>` are cloned with [`IndependentClone`], in the global
+/// allocator.
+///
+/// Note that values allocated directly in a [`Bump`] (rather than in one of its collections) are
+/// never dropped.
+///
+/// An independent clone returned by this trait must satisfy the same guarantees as one from
+/// [`IndependentClone`]; read its documentation for more.
+pub trait IndependentCloneInBump<'bump, S: Speed> {
+    /// The type of an independent clone placed in a `Bump` arena.
+    type Output;
+
+    /// Get a deep clone of a value, which does not share any semantically-important mutable
+    /// state, and whose allocations are made in `bump`.
+    ///
+    /// Read [`IndependentCloneInBump`] for more.
+    #[must_use]
+    fn independent_clone_in_bump(&self, bump: &'bump Bump) -> Self::Output;
+}
+
+
+impl<'bump, S, T> IndependentCloneInBump<'bump, S> for [T]
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    T: IndependentClone<S> + 'bump,
+{
+    type Output = &'bump mut [T];
+
+    #[inline]
+    fn independent_clone_in_bump(&self, bump: &'bump Bump) -> Self::Output {
+        bump.alloc_slice_fill_iter(self.iter().map(T::independent_clone))
+    }
+}
+
+impl<'bump, S: AtLeastAsSlowAs<LinearTime>> IndependentCloneInBump<'bump, S> for str {
+    type Output = &'bump mut Self;
+
+    #[inline]
+    fn independent_clone_in_bump(&self, bump: &'bump Bump) -> Self::Output {
+        bump.alloc_str(self)
+    }
+}
+
+/// Implement `IndependentCloneInBump<'bump, S>` for slice-like containers of `T`, producing a
+/// `bumpalo::collections::Vec<'bump, T>`.
+macro_rules! vec_like {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl<'bump, S, T> IndependentCloneInBump<'bump, S> for $type
+            where
+                S: AtLeastAsSlowAs<LinearTime>,
+                T: IndependentClone<S> + 'bump,
+            {
+                type Output = BumpVec<'bump, T>;
+
+                #[inline]
+                fn independent_clone_in_bump(&self, bump: &'bump Bump) -> Self::Output {
+                    let mut vec = BumpVec::with_capacity_in(self.len(), bump);
+                    vec.extend(self.iter().map(T::independent_clone));
+                    vec
+                }
+            }
+        )*
+    };
+}
+
+vec_like! {
+    Box<[T]>,
+    Vec<T>,
+    BumpVec<'_, T>,
+}
+
+/// Implement `IndependentCloneInBump<'bump, S>` for string-like types, producing a
+/// `bumpalo::collections::String<'bump>`.
+macro_rules! string_like {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl<'bump, S: AtLeastAsSlowAs<LinearTime>> IndependentCloneInBump<'bump, S> for $type {
+                type Output = BumpString<'bump>;
+
+                #[inline]
+                fn independent_clone_in_bump(&self, bump: &'bump Bump) -> Self::Output {
+                    BumpString::from_str_in(self, bump)
+                }
+            }
+        )*
+    };
+}
+
+string_like! {
+    Box<str>,
+    String,
+    BumpString<'_>,
+}
@@ -0,0 +1,168 @@
+use core::{cell::RefCell, marker::PhantomData};
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+
+use crate::independent::IndependentClone;
+use crate::speed::{Speed, AtLeastAsSlowAs, AnySpeed, ConstantTime, NonBlocking};
+
+
+/// A mutable memory location, like [`Cell`], whose value is read by performing an
+/// [independent clone] at speed `S`.
+///
+/// [`Cell::get`] requires `T: Copy`; `CloneCell` allows non-`Copy` values with cheap independent
+/// clones to be used in the same single-threaded interior-mutability patterns. As with `Cell`,
+/// no reference to the inner value is ever handed out through a shared reference.
+///
+/// # Panics
+/// Methods which access the value through a shared reference panic if called while the value is
+/// being cloned by [`get`]; that is, if the independent clone of `T` attempts to access this
+/// same cell (for example, through an `Rc`). This never happens unless `T` can reach the cell
+/// containing it.
+///
+/// [`Cell`]: core::cell::Cell
+/// [`Cell::get`]: core::cell::Cell::get
+/// [independent clone]: IndependentClone
+/// [`get`]: CloneCell::get
+pub struct CloneCell<T, S = AnySpeed> {
+    value:  RefCell<T>,
+    _speed: PhantomData<S>,
+}
+
+impl<T, S> CloneCell<T, S> {
+    /// Create a new `CloneCell` containing the given value.
+    #[inline]
+    #[must_use]
+    pub const fn new(value: T) -> Self {
+        Self {
+            value:  RefCell::new(value),
+            _speed: PhantomData,
+        }
+    }
+
+    /// Set the contained value, dropping the previous value.
+    ///
+    /// # Panics
+    /// Panics if the value is currently being cloned.
+    #[inline]
+    pub fn set(&self, value: T) {
+        drop(self.replace(value));
+    }
+
+    /// Replace the contained value with `value`, and return the previous value.
+    ///
+    /// # Panics
+    /// Panics if the value is currently being cloned.
+    #[inline]
+    pub fn replace(&self, value: T) -> T {
+        self.value.replace(value)
+    }
+
+    /// Swap the values of two `CloneCell`s.
+    ///
+    /// # Panics
+    /// Panics if either value is currently being cloned.
+    #[inline]
+    pub fn swap(&self, other: &Self) {
+        self.value.swap(&other.value);
+    }
+
+    /// Take the contained value, leaving `T::default()` in its place.
+    ///
+    /// # Panics
+    /// Panics if the value is currently being cloned.
+    #[inline]
+    pub fn take(&self) -> T
+    where
+        T: Default,
+    {
+        self.replace(T::default())
+    }
+
+    /// Get a mutable reference to the contained value.
+    ///
+    /// This requires exclusive access to the `CloneCell`, so no cloning or other access can occur
+    /// while the reference is held.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+
+    /// Unwrap the contained value.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T: IndependentClone<S>, S: Speed> CloneCell<T, S> {
+    /// Get an independent clone of the contained value, at speed `S`.
+    ///
+    /// # Panics
+    /// Panics if the independent clone of `T` attempts to set or replace the value of this cell.
+    #[inline]
+    #[must_use]
+    pub fn get(&self) -> T {
+        T::independent_clone(&self.value.borrow())
+    }
+
+    /// Update the contained value by applying `f` to an independent clone of it.
+    ///
+    /// # Panics
+    /// Panics if the independent clone of `T` attempts to set or replace the value of this cell.
+    #[inline]
+    pub fn update<F>(&self, f: F)
+    where
+        F: FnOnce(T) -> T,
+    {
+        self.set(f(self.get()));
+    }
+}
+
+impl<T: IndependentClone<S>, S: Speed> Clone for CloneCell<T, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.get())
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        let source = source.value.borrow();
+        self.get_mut().independent_clone_from(&source);
+    }
+}
+
+impl<T, S, S2> IndependentClone<S2> for CloneCell<T, S>
+where
+    T:  IndependentClone<S2>,
+    S2: AtLeastAsSlowAs<NonBlocking<ConstantTime>>,
+{
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        Self::new(T::independent_clone(&self.value.borrow()))
+    }
+}
+
+impl<T, S> From<T> for CloneCell<T, S> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Default, S> Default for CloneCell<T, S> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Debug, S> Debug for CloneCell<T, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Ok(value) = self.value.try_borrow() {
+            f.debug_struct("CloneCell").field("value", &*value).finish()
+        } else {
+            f.debug_struct("CloneCell").finish_non_exhaustive()
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{independent::IndependentClone, mirrored::MirroredClone, mixed::MixedClone};
+use crate::speed::{NearInstant, ConstantTime, AnySpeed};
+
+
+/// Postfix methods for cloning at common speeds, without speed annotations or fully-qualified
+/// syntax.
+///
+/// For each mode of cloning, three methods are provided:
+/// - `*_instant`, at [`NearInstant`] speed,
+/// - `*_fast`, at [`ConstantTime`] speed, and
+/// - `*_any`, at [`AnySpeed`].
+///
+/// This trait is implemented for every type, and each method is available whenever the type
+/// implements the corresponding cloning trait at that speed.
+///
+/// ```
+/// use std::rc::Rc;
+/// use clone_behavior::CloneBehaviorExt as _;
+///
+/// let list = vec![1_u8, 2, 3];
+/// let deep_copy = list.independent_any();
+///
+/// let shared = Rc::new(list);
+/// let handle = shared.mirrored_fast();
+/// assert_eq!(deep_copy, *handle);
+/// ```
+pub trait CloneBehaviorExt: Sized {
+    /// Get an [independent clone] at [`NearInstant`] speed.
+    ///
+    /// [independent clone]: IndependentClone
+    #[inline]
+    #[must_use]
+    fn independent_instant(&self) -> Self
+    where
+        Self: IndependentClone<NearInstant>,
+    {
+        IndependentClone::<NearInstant>::independent_clone(self)
+    }
+
+    /// Get an [independent clone] at [`ConstantTime`] speed.
+    ///
+    /// [independent clone]: IndependentClone
+    #[inline]
+    #[must_use]
+    fn independent_fast(&self) -> Self
+    where
+        Self: IndependentClone<ConstantTime>,
+    {
+        IndependentClone::<ConstantTime>::independent_clone(self)
+    }
+
+    /// Get an [independent clone] at [`AnySpeed`].
+    ///
+    /// [independent clone]: IndependentClone
+    #[inline]
+    #[must_use]
+    fn independent_any(&self) -> Self
+    where
+        Self: IndependentClone<AnySpeed>,
+    {
+        IndependentClone::<AnySpeed>::independent_clone(self)
+    }
+
+    /// Get a [mirrored clone] at [`NearInstant`] speed.
+    ///
+    /// [mirrored clone]: MirroredClone
+    #[inline]
+    #[must_use]
+    fn mirrored_instant(&self) -> Self
+    where
+        Self: MirroredClone<NearInstant>,
+    {
+        MirroredClone::<NearInstant>::mirrored_clone(self)
+    }
+
+    /// Get a [mirrored clone] at [`ConstantTime`] speed.
+    ///
+    /// [mirrored clone]: MirroredClone
+    #[inline]
+    #[must_use]
+    fn mirrored_fast(&self) -> Self
+    where
+        Self: MirroredClone<ConstantTime>,
+    {
+        MirroredClone::<ConstantTime>::mirrored_clone(self)
+    }
+
+    /// Get a [mirrored clone] at [`AnySpeed`].
+    ///
+    /// [mirrored clone]: MirroredClone
+    #[inline]
+    #[must_use]
+    fn mirrored_any(&self) -> Self
+    where
+        Self: MirroredClone<AnySpeed>,
+    {
+        MirroredClone::<AnySpeed>::mirrored_clone(self)
+    }
+
+    /// Get a [mixed clone] at [`NearInstant`] speed.
+    ///
+    /// [mixed clone]: MixedClone
+    #[inline]
+    #[must_use]
+    fn mixed_instant(&self) -> Self
+    where
+        Self: MixedClone<NearInstant>,
+    {
+        MixedClone::<NearInstant>::mixed_clone(self)
+    }
+
+    /// Get a [mixed clone] at [`ConstantTime`] speed.
+    ///
+    /// [mixed clone]: MixedClone
+    #[inline]
+    #[must_use]
+    fn mixed_fast(&self) -> Self
+    where
+        Self: MixedClone<ConstantTime>,
+    {
+        MixedClone::<ConstantTime>::mixed_clone(self)
+    }
+
+    /// Get a [mixed clone] at [`AnySpeed`].
+    ///
+    /// [mixed clone]: MixedClone
+    #[inline]
+    #[must_use]
+    fn mixed_any(&self) -> Self
+    where
+        Self: MixedClone<AnySpeed>,
+    {
+        MixedClone::<AnySpeed>::mixed_clone(self)
+    }
+}
+
+impl<T> CloneBehaviorExt for T {}
@@ -0,0 +1,148 @@
+//! Free functions for cloning with a speed selected by a turbofish.
+//!
+//! Since most types implement each cloning trait at several speeds, calling a method like
+//! `value.independent_clone()` often requires fully-qualified syntax to select a speed. These
+//! functions take the speed as their first generic parameter instead.
+//!
+//! Helpers which clone the elements of slices and maps into new, pre-sized collections are also
+//! provided.
+//!
+//! ```
+//! use clone_behavior::{fns, IndependentClone, LinearTime};
+//!
+//! let source = vec![1_u8, 2, 3];
+//! let clone = fns::independent::<LinearTime, _>(&source);
+//! // Instead of:
+//! let also_clone = <Vec<u8> as IndependentClone<LinearTime>>::independent_clone(&source);
+//! assert_eq!(clone, also_clone);
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{independent::IndependentClone, mirrored::MirroredClone, mixed::MixedClone};
+use crate::speed::Speed;
+#[cfg(feature = "alloc")]
+use crate::speed::NearInstant;
+
+
+/// Get an [independent clone] of `value` at speed `S`.
+///
+/// [independent clone]: IndependentClone
+#[inline]
+#[must_use]
+pub fn independent<S, T>(value: &T) -> T
+where
+    S: Speed,
+    T: IndependentClone<S>,
+{
+    value.independent_clone()
+}
+
+/// Get a [mirrored clone] of `value` at speed `S`.
+///
+/// [mirrored clone]: MirroredClone
+#[inline]
+#[must_use]
+pub fn mirrored<S, T>(value: &T) -> T
+where
+    S: Speed,
+    T: MirroredClone<S>,
+{
+    value.mirrored_clone()
+}
+
+/// Get a [mixed clone] of `value` at speed `S`.
+///
+/// [mixed clone]: MixedClone
+#[inline]
+#[must_use]
+pub fn mixed<S, T>(value: &T) -> T
+where
+    S: Speed,
+    T: MixedClone<S>,
+{
+    value.mixed_clone()
+}
+
+/// Get a `Vec` of [independent clones] at speed `S` of the elements of `slice`.
+///
+/// The `Vec` is allocated with exactly enough capacity up front. For `Copy` elements, see
+/// [`independent_vec_from_copy_slice`].
+///
+/// [independent clones]: IndependentClone
+#[cfg(feature = "alloc")]
+#[inline]
+#[must_use]
+pub fn independent_vec_from_slice<S, T>(slice: &[T]) -> Vec<T>
+where
+    S: Speed,
+    T: IndependentClone<S>,
+{
+    let mut vec = Vec::with_capacity(slice.len());
+    vec.extend(slice.iter().map(T::independent_clone));
+    vec
+}
+
+/// Get a `Vec` of independent clones of the elements of `slice`, by copying the slice's memory.
+///
+/// This is a fast path for `Copy` elements whose independent clones are copies, as is the case
+/// for every `Copy` type implementing [`IndependentClone<NearInstant>`] in this crate.
+#[cfg(feature = "alloc")]
+#[inline]
+#[must_use]
+pub fn independent_vec_from_copy_slice<T>(slice: &[T]) -> Vec<T>
+where
+    T: Copy + IndependentClone<NearInstant>,
+{
+    slice.to_vec()
+}
+
+/// Get a `Vec` of [mirrored clones] at speed `S` of the elements of `slice`.
+///
+/// The `Vec` is allocated with exactly enough capacity up front.
+///
+/// [mirrored clones]: MirroredClone
+#[cfg(feature = "alloc")]
+#[inline]
+#[must_use]
+pub fn mirrored_vec_from_slice<S, T>(slice: &[T]) -> Vec<T>
+where
+    S: Speed,
+    T: MirroredClone<S>,
+{
+    let mut vec = Vec::with_capacity(slice.len());
+    vec.extend(slice.iter().map(T::mirrored_clone));
+    vec
+}
+
+/// Collect [independent clones] at speed `S` of the keys and values of `map` into a new map.
+///
+/// `map` is anything which iterates over pairs of references to keys and values, such as a
+/// `&HashMap<K, V>` or `&BTreeMap<K, V>`, and the output may be any collection of key-value
+/// pairs. Collections like `HashMap` use the iterator's size hint to pre-size their allocation.
+///
+/// ```
+/// use std::collections::{BTreeMap, HashMap};
+/// use clone_behavior::{fns, LinearTime};
+///
+/// let map = HashMap::from([(1_u8, vec![1_u8]), (2, vec![2, 2])]);
+/// let copy: BTreeMap<u8, Vec<u8>> = fns::independent_clone_map::<LinearTime, _, _, _, _>(&map);
+/// assert_eq!(copy[&2], [2, 2]);
+/// ```
+///
+/// [independent clones]: IndependentClone
+#[inline]
+#[must_use]
+pub fn independent_clone_map<'a, S, K, V, I, M>(map: I) -> M
+where
+    S: Speed,
+    K: IndependentClone<S> + 'a,
+    V: IndependentClone<S> + 'a,
+    I: IntoIterator<Item = (&'a K, &'a V)>,
+    M: FromIterator<(K, V)>,
+{
+    map.into_iter()
+        .map(|(key, value)| (key.independent_clone(), value.independent_clone()))
+        .collect()
+}
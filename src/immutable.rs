@@ -0,0 +1,166 @@
+#![expect(clippy::absolute_paths, reason = "there's a lot of random types used")]
+
+use crate::call_varargs_macro;
+use crate::independent::IndependentClone;
+use crate::speed::NearInstant;
+
+
+/// Indicates that a type has no interior mutability, so that a shared reference to it can never
+/// observe a change to its semantically-important state.
+///
+/// This is similar to the unstable `Freeze` auto trait, except that it must be implemented
+/// manually, and that indirection is permitted: `Box<T>` and `Vec<T>` are `Immutable` when `T` is,
+/// since their contents can only be mutated through a unique reference.
+///
+/// A shared reference `&T` is always a [mirrored clone] of its source. If `T` is `Immutable`, it is
+/// simultaneously an [independent clone]; no mutable state is shared, since there is no mutable
+/// state. Therefore, `&T` implements [`IndependentClone<NearInstant>`] whenever `T: Immutable`
+/// (and every slower speed, since `&T` is [`NonRecursive`]).
+///
+/// Types with reference counts, like `Rc<T>` and `Arc<T>`, should not implement this trait, as
+/// the reference count is observable shared mutable state.
+///
+/// [independent clone]: IndependentClone
+/// [mirrored clone]: crate::MirroredClone
+/// [`NonRecursive`]: crate::NonRecursive
+pub trait Immutable {}
+
+
+impl<T: ?Sized + Immutable> IndependentClone<NearInstant> for &T {
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        self
+    }
+}
+
+macro_rules! immutable {
+    ($($({for $($bounds:tt)+})? $type:ty),* $(,)?) => {
+        $(
+            impl<$($($bounds)+)?> Immutable for $type {}
+        )*
+    };
+}
+
+immutable! {
+    i8, i16, i32, i64, i128, isize,
+    u8, u16, u32, u64, u128, usize,
+    f32, f64, bool, char, str, (),
+    {for T: ?Sized + Immutable} &T,
+    {for T: Immutable} [T],
+    {for T: Immutable, const N: usize} [T; N],
+    {for T: Immutable} Option<T>,
+    {for T: Immutable, E: Immutable} Result<T, E>,
+    core::alloc::Layout,
+    core::any::TypeId,
+    core::cmp::Ordering,
+    {for T: Immutable} core::cmp::Reverse<T>,
+    core::convert::Infallible,
+    {for H} core::hash::BuildHasherDefault<H>,
+    {for T: ?Sized} core::marker::PhantomData<T>,
+    core::marker::PhantomPinned,
+    {for T} core::mem::Discriminant<T>,
+    {for T: Immutable} core::mem::ManuallyDrop<T>,
+    {for T: Immutable} core::num::Wrapping<T>,
+    core::ops::RangeFull,
+    core::sync::atomic::Ordering,
+    core::time::Duration,
+}
+
+macro_rules! non_zero {
+    ($($num:ident),* $(,)?) => {
+        $(
+            impl Immutable for core::num::NonZero<$num> {}
+        )*
+    };
+}
+
+non_zero!(
+    i8, i16, i32, i64, i128, isize,
+    u8, u16, u32, u64, u128, usize,
+);
+
+#[cfg(feature = "alloc")]
+immutable! {
+    {for T: ?Sized + Immutable} alloc::boxed::Box<T>,
+    {for T: Immutable} alloc::vec::Vec<T>,
+    alloc::string::String,
+}
+
+#[cfg(feature = "std")]
+immutable! {
+    std::ffi::CStr,
+    std::ffi::OsStr,
+    std::hash::RandomState,
+    std::path::Path,
+    std::time::Instant,
+    std::thread::ThreadId,
+}
+
+#[cfg(feature = "camino")]
+immutable! {
+    camino::Utf8Path,
+}
+
+#[cfg(feature = "chrono")]
+immutable! {
+    chrono::NaiveDate,
+    chrono::NaiveTime,
+    chrono::NaiveDateTime,
+    chrono::DateTime<chrono::Utc>,
+    chrono::DateTime<chrono::FixedOffset>,
+    chrono::Utc,
+    chrono::FixedOffset,
+    chrono::TimeDelta,
+}
+
+#[cfg(feature = "jiff")]
+immutable! {
+    jiff::Timestamp,
+    jiff::Span,
+    jiff::civil::Date,
+    jiff::civil::Time,
+    jiff::civil::DateTime,
+    jiff::civil::ISOWeekDate,
+    jiff::civil::Weekday,
+}
+
+#[cfg(feature = "num")]
+immutable! {
+    num_bigint::BigInt,
+    num_bigint::BigUint,
+    {for T: Immutable} num_rational::Ratio<T>,
+    {for T: Immutable} num_complex::Complex<T>,
+}
+
+#[cfg(feature = "rust_decimal")]
+immutable! {
+    rust_decimal::Decimal,
+}
+
+#[cfg(feature = "time")]
+immutable! {
+    time::Date,
+    time::Time,
+    time::OffsetDateTime,
+    time::PrimitiveDateTime,
+    time::Duration,
+    time::UtcOffset,
+}
+
+#[cfg(feature = "ulid")]
+immutable! {
+    ulid::Ulid,
+}
+
+#[cfg(feature = "uuid")]
+immutable! {
+    uuid::Uuid,
+}
+
+macro_rules! tuple {
+    ($($args:ident),+) => {
+        impl<$($args: Immutable),+> Immutable for ($($args,)+) {}
+    };
+}
+
+call_varargs_macro!(tuple);
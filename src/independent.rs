@@ -1,8 +1,12 @@
 #![expect(clippy::absolute_paths, reason = "there's a lot of random types used")]
 #![warn(clippy::missing_inline_in_public_items, reason = "almost everything is very short")]
 
-use crate::call_varargs_macro;
-use crate::speed::{Speed, NearInstant, ConstantTime, LogTime, AnySpeed};
+use crate::{call_varargs_macro, immutable::Immutable};
+#[cfg(any(feature = "alloc", feature = "once_cell"))]
+use crate::trace_clone;
+use crate::speed::{Speed, AtLeastAsSlowAs, NearInstant, ConstantTime, NonBlocking};
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+use crate::speed::LinearTime;
 
 
 /// Get deep clones of a value, which do not share any semantically-important mutable state.
@@ -40,6 +44,19 @@ use crate::speed::{Speed, NearInstant, ConstantTime, LogTime, AnySpeed};
 ///   it, then the reference count used to determine that is shared mutable state. This can be
 ///   avoided by simply giving the new independent clone an unique owned copy of the data, as
 ///   should likely be done anyway.
+///
+/// # `Box<T>`
+/// There is no generic implementation for `Box<T>` (or `Pin<Box<T>>`). Since `Box` is a
+/// fundamental type, any crate may implement [`NonRecursive`] for `Box<TheirType>`, so a generic
+/// implementation would overlap with the blanket implementations for `NonRecursive` types. For the
+/// same reason, a crate may implement `IndependentClone` for `Box<TheirType>` itself, which
+/// [`impl_for_box!`] does at every speed `TheirType` supports. Alternatively,
+/// [`independent_clone_box`] gets an independent clone of any `T: IndependentClone<S>` in a new
+/// box.
+///
+/// [`NonRecursive`]: crate::NonRecursive
+/// [`impl_for_box!`]: crate::impl_for_box
+/// [`independent_clone_box`]: crate::independent_clone_box
 pub trait IndependentClone<S: Speed>: Sized {
     /// Get a deep clone of a value, which does not share any semantically-important mutable state.
     ///
@@ -51,6 +68,15 @@ pub trait IndependentClone<S: Speed>: Sized {
     /// Read [`IndependentClone`] for more.
     #[must_use]
     fn independent_clone(&self) -> Self;
+
+    /// Overwrite `self` with an independent clone of `source`.
+    ///
+    /// Like [`Clone::clone_from`], this may be overridden to reuse the resources of `self`, such
+    /// as its allocations, instead of performing a new independent clone.
+    #[inline]
+    fn independent_clone_from(&mut self, source: &Self) {
+        *self = source.independent_clone();
+    }
 }
 
 
@@ -99,21 +125,265 @@ non_recursive_near_instant! {
     core::any::TypeId,
     core::cmp::Ordering,
     core::convert::Infallible,
+    {for H} core::hash::BuildHasherDefault<H>,
     {for T} core::iter::Empty<T>,
     {for T: ?Sized} core::marker::PhantomData<T>,
     core::marker::PhantomPinned,
     {for T} core::mem::Discriminant<T>,
+    // A copy of a `Copy` type is only independent if that type has an independent clone; for
+    // instance, `&Cell<u32>` is `Copy`, but its copies share the cell.
+    {for T: Copy + IndependentClone<NearInstant>} core::mem::MaybeUninit<T>,
+    core::net::IpAddr,
+    core::net::Ipv4Addr,
+    core::net::Ipv6Addr,
+    core::net::SocketAddr,
+    core::net::SocketAddrV4,
+    core::net::SocketAddrV6,
     core::ops::RangeFull,
     core::sync::atomic::Ordering,
     core::time::Duration,
 }
 
+/// Iterators whose `Clone` implementation is an independent, near-instant clone.
+///
+/// Iterator adapters, like `Rev<I>` or `Take<I>`, do not expose their state except through
+/// `Clone`, so an independent clone of an adapter can only be made with its `Clone` implementation,
+/// which is independent exactly when the `Clone` implementations of the adapted iterators are.
+pub(crate) trait CloneIsIndependent: Clone {}
+
+macro_rules! independent_iter {
+    ($($({for $($bounds:tt)+})? $type:ty),* $(,)?) => {
+        $(
+            impl<$($($bounds)+)?> CloneIsIndependent for $type {}
+
+            non_recursive_near_instant! {
+                $({for $($bounds)+})? $type,
+            }
+        )*
+    };
+}
+
+// Iterators over immutable borrowed data only have their position as mutable state, and copies of
+// `Copy` values with independent clones are independent.
+independent_iter! {
+    {for T: Immutable} core::slice::Iter<'_, T>,
+    core::str::Bytes<'_>,
+    core::str::CharIndices<'_>,
+    core::str::Chars<'_>,
+    {for T: Copy + IndependentClone<NearInstant>} core::iter::Once<T>,
+    {for T: Copy + IndependentClone<NearInstant>} core::iter::Repeat<T>,
+    {for T: Copy + IndependentClone<NearInstant>, const N: usize} core::array::IntoIter<T, N>,
+    {for I: CloneIsIndependent} core::iter::Rev<I>,
+    {for I: CloneIsIndependent} core::iter::Enumerate<I>,
+    {for I: CloneIsIndependent} core::iter::Take<I>,
+    {for I: CloneIsIndependent} core::iter::Skip<I>,
+    {for A: CloneIsIndependent, B: CloneIsIndependent} core::iter::Chain<A, B>,
+}
+
+// Persistent collections share their nodes between clones, and copy a node before mutating it if
+// it is shared. The contents (and hasher) are reachable from every clone through shared
+// references, so they must be `Immutable`.
+#[cfg(feature = "im")]
+non_recursive_near_instant! {
+    {for A: Clone + Immutable, S: Immutable} im::HashSet<A, S>,
+    {for K: Clone + Immutable, V: Clone + Immutable, S: Immutable} im::HashMap<K, V, S>,
+    {for A: Immutable} im::OrdSet<A>,
+    {for K: Immutable, V: Immutable} im::OrdMap<K, V>,
+}
+
+#[cfg(feature = "imbl")]
+non_recursive_near_instant! {
+    {
+        for A: Clone + Immutable, S: Clone + Immutable, P: imbl::shared_ptr::SharedPointerKind
+    } imbl::GenericHashSet<A, S, P>,
+    {
+        for K: Clone + Immutable, V: Clone + Immutable, S: Clone + Immutable,
+        P: imbl::shared_ptr::SharedPointerKind
+    } imbl::GenericHashMap<K, V, S, P>,
+    {
+        for A: Immutable, P: imbl::shared_ptr::SharedPointerKind
+    } imbl::GenericOrdSet<A, P>,
+    {
+        for K: Immutable, V: Immutable, P: imbl::shared_ptr::SharedPointerKind
+    } imbl::GenericOrdMap<K, V, P>,
+}
+
+/// Vectors short enough to be stored inline are cloned by cloning each of their elements, which
+/// takes time linear in the size of those elements. Longer vectors share their nodes.
+#[cfg(feature = "im")]
+impl<A: Clone + Immutable> IndependentClone<LinearTime> for im::Vector<A> {
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        self.clone()
+    }
+}
+
+/// Vectors short enough to be stored inline are cloned by cloning each of their elements, which
+/// takes time linear in the size of those elements. Longer vectors share their nodes.
+#[cfg(feature = "imbl")]
+impl<A, P> IndependentClone<LinearTime> for imbl::GenericVector<A, P>
+where
+    A: Clone + Immutable,
+    P: imbl::shared_ptr::SharedPointerKind,
+{
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        self.clone()
+    }
+}
+
+// `rpds` never clones elements, instead sharing them between nodes.
+#[cfg(feature = "rpds")]
+non_recursive_near_instant! {
+    {for T: Immutable, P: archery::SharedPointerKind} rpds::Vector<T, P>,
+    {for T: Immutable, P: archery::SharedPointerKind} rpds::List<T, P>,
+    {for T: Immutable, P: archery::SharedPointerKind} rpds::Stack<T, P>,
+    {for T: Immutable, P: archery::SharedPointerKind} rpds::Queue<T, P>,
+    {
+        for T: Eq + core::hash::Hash + Immutable, P: archery::SharedPointerKind,
+        H: core::hash::BuildHasher + Clone + Immutable
+    } rpds::HashTrieSet<T, P, H>,
+    {
+        for K: Eq + core::hash::Hash + Immutable, V: Immutable, P: archery::SharedPointerKind,
+        H: core::hash::BuildHasher + Clone + Immutable
+    } rpds::HashTrieMap<K, V, P, H>,
+    {
+        for T: Ord + Immutable, P: archery::SharedPointerKind
+    } rpds::RedBlackTreeSet<T, P>,
+    {
+        for K: Ord + Immutable, V: Immutable, P: archery::SharedPointerKind
+    } rpds::RedBlackTreeMap<K, V, P>,
+}
+
+#[cfg(feature = "hashbrown")]
+non_recursive_near_instant! {
+    hashbrown::DefaultHashBuilder,
+}
+
+// `chrono::Duration` is an alias of `TimeDelta`.
+#[cfg(feature = "chrono")]
+non_recursive_near_instant! {
+    chrono::NaiveDate,
+    chrono::NaiveTime,
+    chrono::NaiveDateTime,
+    chrono::DateTime<chrono::Utc>,
+    chrono::DateTime<chrono::FixedOffset>,
+    chrono::Utc,
+    chrono::FixedOffset,
+    chrono::TimeDelta,
+}
+
+#[cfg(feature = "jiff")]
+non_recursive_near_instant! {
+    jiff::Timestamp,
+    jiff::Span,
+    jiff::civil::Date,
+    jiff::civil::Time,
+    jiff::civil::DateTime,
+    jiff::civil::ISOWeekDate,
+    jiff::civil::Weekday,
+}
+
+// A `Zoned` holds its time zone in an `Arc`, but time zones are never mutated after construction,
+// so sharing one is fine.
+#[cfg(feature = "jiff")]
+non_recursive_near_instant! {
+    jiff::Zoned,
+}
+
+#[cfg(feature = "rust_decimal")]
+non_recursive_near_instant! {
+    rust_decimal::Decimal,
+}
+
+#[cfg(feature = "time")]
+non_recursive_near_instant! {
+    time::Date,
+    time::Time,
+    time::OffsetDateTime,
+    time::PrimitiveDateTime,
+    time::Duration,
+    time::UtcOffset,
+}
+
+#[cfg(feature = "ulid")]
+non_recursive_near_instant! {
+    ulid::Ulid,
+}
+
+#[cfg(feature = "uuid")]
+non_recursive_near_instant! {
+    uuid::Uuid,
+}
+
 #[cfg(feature = "std")]
 non_recursive_near_instant! {
+    std::hash::DefaultHasher,
+    std::hash::RandomState,
+    std::io::SeekFrom,
     std::time::Instant,
+    std::time::SystemTime,
     std::thread::ThreadId,
 }
 
+#[cfg(feature = "std")]
+#[allow(
+    clippy::std_instead_of_core,
+    reason = "`core::io` is unstable, and only nightly Clippy suggests it",
+)]
+const _: () = {
+    non_recursive_near_instant! {
+        std::io::Empty,
+        std::io::Sink,
+    }
+};
+
+// Error types
+non_recursive_near_instant! {
+    core::alloc::LayoutError,
+    core::array::TryFromSliceError,
+    core::char::CharTryFromError,
+    core::char::ParseCharError,
+    core::char::TryFromCharError,
+    core::fmt::Error,
+    core::num::ParseFloatError,
+    core::num::ParseIntError,
+    core::num::TryFromIntError,
+    core::str::ParseBoolError,
+    core::str::Utf8Error,
+}
+
+#[cfg(feature = "alloc")]
+non_recursive_near_instant! {
+    alloc::collections::TryReserveError,
+}
+
+#[cfg(feature = "allocator_api")]
+non_recursive_near_instant! {
+    core::alloc::AllocError,
+}
+
+#[cfg(feature = "std")]
+#[allow(
+    clippy::std_instead_of_core,
+    reason = "`core::io` is unstable, and only nightly Clippy suggests it",
+)]
+const _: () = {
+    non_recursive_near_instant! {
+        std::io::ErrorKind,
+    }
+};
+
+#[cfg(feature = "std")]
+non_recursive_near_instant! {
+    std::path::StripPrefixError,
+    std::sync::mpsc::RecvError,
+    std::sync::mpsc::RecvTimeoutError,
+    std::sync::mpsc::TryRecvError,
+    std::thread::AccessError,
+    std::time::SystemTimeError,
+}
+
 macro_rules! atomic {
     ($($name:ident $bits:literal),* $(,)?) => {
         $(
@@ -137,6 +407,32 @@ atomic! {
     AtomicIsize "ptr", AtomicUsize "ptr",
 }
 
+#[cfg(feature = "portable-atomic")]
+macro_rules! portable_atomic {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl IndependentClone<NearInstant> for portable_atomic::$name {
+                #[inline]
+                fn independent_clone(&self) -> Self {
+                    Self::new(self.load(portable_atomic::Ordering::Relaxed))
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "portable-atomic")]
+portable_atomic! {
+    AtomicBool,
+    AtomicI8,    AtomicU8,
+    AtomicI16,   AtomicU16,
+    AtomicI32,   AtomicU32,
+    AtomicI64,   AtomicU64,
+    AtomicI128,  AtomicU128,
+    AtomicIsize, AtomicUsize,
+    AtomicF32,   AtomicF64,
+}
+
 macro_rules! function {
     ($($args:ident),*) => {
         impl<R, $($args),*> IndependentClone<NearInstant> for fn($($args),*) -> R {
@@ -151,40 +447,36 @@ macro_rules! function {
 function!();
 call_varargs_macro!(function);
 
-macro_rules! make_tuple_macro {
-    ($name:ident, $speed:ident, $dollar:tt) => {
-        macro_rules! $name {
-            ($dollar($dollar args:ident),+) => {
-                impl<$dollar($dollar args: IndependentClone<$speed>),+> IndependentClone<$speed>
-                for ($dollar($dollar args,)+)
-                {
-                    #[inline]
-                    fn independent_clone(&self) -> Self {
-                        #[expect(
-                            non_snake_case,
-                            reason = "using `Tn` as the variable of type `Tn`",
-                        )]
-                        let ($dollar($dollar args,)+) = self;
-                        (
-                            $dollar($dollar args.independent_clone(),)+
-                        )
-                    }
-                }
-            };
+// Every element is cloned at the same speed `S`. Read `SpeedMax` for how this supports elements
+// with different fastest speeds.
+macro_rules! tuple {
+    ($($args:ident),+) => {
+        impl<S, $($args: IndependentClone<S>),+> IndependentClone<S> for ($($args,)+)
+        where
+            S: AtLeastAsSlowAs<NonBlocking<ConstantTime>>,
+        {
+            #[inline]
+            fn independent_clone(&self) -> Self {
+                #[expect(
+                    non_snake_case,
+                    reason = "using `Tn` as the variable of type `Tn`",
+                )]
+                let ($($args,)+) = self;
+                (
+                    $($args.independent_clone(),)+
+                )
+            }
         }
     };
 }
 
-make_tuple_macro!(tuple_constant, ConstantTime, $);
-make_tuple_macro!(tuple_log, LogTime, $);
-make_tuple_macro!(tuple_any, AnySpeed, $);
+call_varargs_macro!(tuple);
 
-call_varargs_macro!(tuple_constant);
-call_varargs_macro!(tuple_log);
-call_varargs_macro!(tuple_any);
-
-macro_rules! constant_or_slower {
+/// Implement `IndependentClone<S>` for every speed `S` which is at least as slow as the first
+/// speed given.
+macro_rules! speed_or_slower {
     (
+        $fastest:ty;
         $(
             $(#[$meta:meta])*
             $({for ($($special_bounds:ident)*) {$($where_bounds:tt)*} $($bounds:tt)*})?
@@ -194,36 +486,11 @@ macro_rules! constant_or_slower {
         $(,)?
     ) => {
         $(
-            impl<$($($special_bounds: IndependentClone<ConstantTime>,)* $($bounds)*)?>
-                IndependentClone<ConstantTime>
-            for $type
-            where
-                $($($where_bounds)*)?
-            {
-                $(#[$meta])*
-                #[inline]
-                fn independent_clone(&$self) -> Self {
-                    $($body)*
-                }
-            }
-
-            impl<$($($special_bounds: IndependentClone<LogTime>,)* $($bounds)*)?>
-                IndependentClone<LogTime>
-            for $type
-            where
-                $($($where_bounds)*)?
-            {
-                $(#[$meta])*
-                #[inline]
-                fn independent_clone(&$self) -> Self {
-                    $($body)*
-                }
-            }
-
-            impl<$($($special_bounds: IndependentClone<AnySpeed>,)* $($bounds)*)?>
-                IndependentClone<AnySpeed>
+            impl<S, $($($special_bounds: IndependentClone<S>,)* $($bounds)*)?>
+                IndependentClone<S>
             for $type
             where
+                S: AtLeastAsSlowAs<$fastest>,
                 $($($where_bounds)*)?
             {
                 $(#[$meta])*
@@ -236,7 +503,8 @@ macro_rules! constant_or_slower {
     };
 }
 
-constant_or_slower! {
+speed_or_slower! {
+    NonBlocking<ConstantTime>;
     {for (T) {T: ?Sized} const N: usize} [T; N] {|self| {
         self.each_ref().map(T::independent_clone)
     }},
@@ -251,25 +519,159 @@ constant_or_slower! {
     {for (T) {}} core::mem::ManuallyDrop<T> {|self| {
         Self::new(T::independent_clone(self))
     }},
+    {for (T) {}} core::cmp::Reverse<T> {|self| {
+        core::cmp::Reverse(T::independent_clone(&self.0))
+    }},
+    {for (T) {}} core::num::Saturating<T> {|self| {
+        core::num::Saturating(T::independent_clone(&self.0))
+    }},
+    {for (T) {}} core::num::Wrapping<T> {|self| {
+        core::num::Wrapping(T::independent_clone(&self.0))
+    }},
+    {for (B C) {}} core::ops::ControlFlow<B, C> {|self| {
+        match self {
+            Self::Continue(val) => Self::Continue(C::independent_clone(val)),
+            Self::Break(val)    => Self::Break(B::independent_clone(val)),
+        }
+    }},
+    {for (T) {}} core::task::Poll<T> {|self| {
+        match self {
+            Self::Ready(val) => Self::Ready(T::independent_clone(val)),
+            Self::Pending    => Self::Pending,
+        }
+    }},
     {for (T) {T: Copy}} core::cell::Cell<T> {|self| {
         Self::new(T::independent_clone(&self.get()))
     }},
     /// # Panics
-    /// Panics if the value is currently mutably borrowed.
+    /// Panics if the value is currently mutably borrowed. Use [`TryIndependentClone`] to instead
+    /// get an error.
+    ///
+    /// [`TryIndependentClone`]: crate::TryIndependentClone
     {for (T) {}} core::cell::RefCell<T> {|self| {
         Self::new(T::independent_clone(&self.borrow()))
     }},
+    {for (T) {}} core::ops::Range<T> {|self| {
+        T::independent_clone(&self.start)..T::independent_clone(&self.end)
+    }},
+    {for (T) {}} core::ops::RangeFrom<T> {|self| {
+        T::independent_clone(&self.start)..
+    }},
+    {for (T) {}} core::ops::RangeTo<T> {|self| {
+        ..T::independent_clone(&self.end)
+    }},
+    {for (T) {}} core::ops::RangeToInclusive<T> {|self| {
+        ..=T::independent_clone(&self.end)
+    }},
+    /// An exhausted range remains exhausted in the clone, which is why `RangeInclusive<T>` is
+    /// required to be an iterator.
+    {for (T) {
+        T: PartialOrd,
+        core::ops::RangeInclusive<T>: Iterator,
+    }} core::ops::RangeInclusive<T> {|self| {
+        let mut range = Self::new(
+            T::independent_clone(self.start()),
+            T::independent_clone(self.end()),
+        );
+        // An exhausted `RangeInclusive` has equal bounds, so advancing the new range once
+        // exhausts it.
+        if self.is_empty() && !range.is_empty() {
+            range.next();
+        }
+        range
+    }},
+    {for (T) {}} core::ops::Bound<T> {|self| {
+        self.as_ref().map(T::independent_clone)
+    }},
+    /// An uninitialized cell is cloned into an uninitialized cell.
+    ///
+    /// `LazyCell` is not supported, as an uninitialized clone would need a new initializer of the
+    /// same type as the source's; use a `OnceCell` instead.
+    {for (T) {}} core::cell::OnceCell<T> {|self| {
+        self.get().map_or_else(Self::new, |val| Self::from(T::independent_clone(val)))
+    }},
+}
+
+#[cfg(feature = "num")]
+speed_or_slower! {
+    NonBlocking<ConstantTime>;
+    {for (T) {}} num_rational::Ratio<T> {|self| {
+        Self::new_raw(T::independent_clone(self.numer()), T::independent_clone(self.denom()))
+    }},
+    {for (T) {}} num_complex::Complex<T> {|self| {
+        Self::new(T::independent_clone(&self.re), T::independent_clone(&self.im))
+    }},
+}
+
+#[cfg(feature = "std")]
+speed_or_slower! {
+    NonBlocking<ConstantTime>;
+    /// An uninitialized lock, or a lock which is currently being initialized by another thread, is
+    /// cloned into an uninitialized lock.
+    ///
+    /// `LazyLock` is not supported, as an uninitialized clone would need a new initializer of the
+    /// same type as the source's; use a `OnceLock` instead.
+    {for (T) {}} std::sync::OnceLock<T> {|self| {
+        trace_clone!("independent", S);
+        self.get().map_or_else(Self::new, |val| Self::from(T::independent_clone(val)))
+    }},
+}
+
+#[cfg(feature = "std")]
+#[allow(
+    clippy::std_instead_of_core,
+    reason = "`core::io` is unstable, and only nightly Clippy suggests it",
+)]
+const _: () = {
+    speed_or_slower! {
+        NonBlocking<ConstantTime>;
+        {for (T) {}} std::io::Cursor<T> {|self| {
+            trace_clone!("independent", S);
+            let mut cursor = Self::new(T::independent_clone(self.get_ref()));
+            cursor.set_position(self.position());
+            cursor
+        }},
+    }
+};
+
+#[cfg(feature = "once_cell")]
+speed_or_slower! {
+    NonBlocking<ConstantTime>;
+    /// An uninitialized cell is cloned into an uninitialized cell.
+    {for (T) {}} once_cell::unsync::OnceCell<T> {|self| {
+        trace_clone!("independent", S);
+        self.get().map_or_else(Self::new, |val| Self::from(T::independent_clone(val)))
+    }},
+    /// An uninitialized cell, or a cell which is currently being initialized by another thread, is
+    /// cloned into an uninitialized cell.
+    {for (T) {}} once_cell::sync::OnceCell<T> {|self| {
+        trace_clone!("independent", S);
+        self.get().map_or_else(Self::new, |val| Self::from(T::independent_clone(val)))
+    }},
+}
+
+#[cfg(feature = "exclusive_wrapper")]
+speed_or_slower! {
+    NonBlocking<ConstantTime>;
+    /// The inner value can only be accessed through a shared reference if it is `Sync`.
+    {for (T) {T: Sync}} core::sync::SyncView<T> {|self| {
+        Self::new(T::independent_clone(self.as_ref()))
+    }},
 }
 
 #[cfg(feature = "alloc")]
-constant_or_slower! {
+speed_or_slower! {
+    ConstantTime;
     {for (T) {T: ?Sized}} alloc::rc::Rc<T> {|self| {
+        trace_clone!("independent", S);
         Self::new(T::independent_clone(self))
     }},
     {for (T) {T: ?Sized}} core::pin::Pin<alloc::rc::Rc<T>> {|self| {
+        trace_clone!("independent", S);
         alloc::rc::Rc::pin(T::independent_clone(self))
     }},
     {for (T) {T: ?Sized}} alloc::rc::Weak<T> {|self| {
+        trace_clone!("independent", S);
         if let Some(rc) = self.upgrade() {
             alloc::rc::Rc::downgrade(&alloc::rc::Rc::new(T::independent_clone(&rc)))
         } else {
@@ -277,12 +679,15 @@ constant_or_slower! {
         }
     }},
     {for (T) {T: ?Sized}} alloc::sync::Arc<T> {|self| {
+        trace_clone!("independent", S);
         Self::new(T::independent_clone(self))
     }},
     {for (T) {T: ?Sized}} core::pin::Pin<alloc::sync::Arc<T>> {|self| {
+        trace_clone!("independent", S);
         alloc::sync::Arc::pin(T::independent_clone(self))
     }},
     {for (T) {T: ?Sized}} alloc::sync::Weak<T> {|self| {
+        trace_clone!("independent", S);
         if let Some(arc) = self.upgrade() {
             alloc::sync::Arc::downgrade(&alloc::sync::Arc::new(T::independent_clone(&arc)))
         } else {
@@ -292,10 +697,14 @@ constant_or_slower! {
 }
 
 #[cfg(feature = "std")]
-constant_or_slower! {
+speed_or_slower! {
+    ConstantTime;
     /// # Panics
-    /// Panics if the `RwLock` is poisoned.
+    /// Panics if the `RwLock` is poisoned. Use [`TryIndependentClone`] to instead get an error.
+    ///
+    /// [`TryIndependentClone`]: crate::TryIndependentClone
     {for (T) {}} std::sync::RwLock<T> {|self| {
+        trace_clone!("independent", S);
         let lock_result: Result<_, std::sync::PoisonError<_>> = self.read();
         #[expect(clippy::unwrap_used, reason = "Unwrapping poison")]
         Self::new(T::independent_clone(&lock_result.unwrap()))
@@ -303,27 +712,118 @@ constant_or_slower! {
     /// # Panics or Deadlocks
     /// Panics if the `Mutex` is poisoned.
     ///
-    /// Will either panic or deadlock if the current thread already holds the mutex.
+    /// Will either panic or deadlock if the current thread already holds the mutex. Use
+    /// [`TryIndependentClone`] to instead get an error.
+    ///
+    /// [`TryIndependentClone`]: crate::TryIndependentClone
     {for (T) {}} std::sync::Mutex<T> {|self| {
+        trace_clone!("independent", S);
         let lock_result: Result<_, std::sync::PoisonError<_>> = self.lock();
         #[expect(clippy::unwrap_used, reason = "Unwrapping poison")]
         Self::new(T::independent_clone(&lock_result.unwrap()))
     }},
 }
 
-// constant or slower: ranges, ops::Bound,
+#[cfg(feature = "triomphe")]
+speed_or_slower! {
+    ConstantTime;
+    {for (T) {}} triomphe::Arc<T> {|self| {
+        Self::new(T::independent_clone(self))
+    }},
+    {for (T) {}} triomphe::UniqueArc<T> {|self| {
+        Self::new(T::independent_clone(self))
+    }},
+}
+
+// An `ArcSwap<T>` shared behind an `Arc`, like `Arc<ArcSwap<T>>`, is already a `MirroredClone`
+// handle to the same swappable value.
+#[cfg(feature = "arc-swap")]
+speed_or_slower! {
+    ConstantTime;
+    /// The currently-stored value is independently cloned into a new `Arc`, which is stored in a
+    /// new `ArcSwap`. Later stores to either `ArcSwap` are not observed by the other. The `Arc`
+    /// itself is not shared, as its reference count is observable shared state.
+    {for (T) {}} arc_swap::ArcSwap<T> {|self| {
+        Self::from_pointee(T::independent_clone(&self.load()))
+    }},
+    /// The currently-stored value (if any) is independently cloned into a new `Arc`, which is
+    /// stored in a new `ArcSwapOption`. Later stores to either `ArcSwapOption` are not observed by
+    /// the other.
+    {for (T) {}} arc_swap::ArcSwapOption<T> {|self| {
+        Self::from_pointee(self.load().as_deref().map(T::independent_clone))
+    }},
+}
+
+#[cfg(feature = "critical-section")]
+speed_or_slower! {
+    ConstantTime;
+    /// The value is cloned inside a critical section.
+    {for (T) {}} critical_section::Mutex<T> {|self| {
+        critical_section::with(|cs| Self::new(T::independent_clone(self.borrow(cs))))
+    }},
+}
+
+#[cfg(feature = "embassy-sync")]
+speed_or_slower! {
+    ConstantTime;
+    {for (T) {} R: embassy_sync::blocking_mutex::raw::RawMutex}
+    embassy_sync::blocking_mutex::Mutex<R, T> {|self| {
+        self.lock(|val| Self::new(T::independent_clone(val)))
+    }},
+}
+
+#[cfg(feature = "parking_lot")]
+speed_or_slower! {
+    ConstantTime;
+    /// # Deadlocks
+    /// Deadlocks if the current thread already holds a write lock, or holds a read lock while
+    /// another thread is waiting for a write lock.
+    {for (T) {}} parking_lot::RwLock<T> {|self| {
+        Self::new(T::independent_clone(&self.read()))
+    }},
+    /// # Deadlocks
+    /// Deadlocks if the current thread already holds the mutex.
+    {for (T) {}} parking_lot::Mutex<T> {|self| {
+        Self::new(T::independent_clone(&self.lock()))
+    }},
+    /// # Deadlocks
+    /// Deadlocks if the current thread already holds the mutex.
+    {for (T) {}} parking_lot::FairMutex<T> {|self| {
+        Self::new(T::independent_clone(&self.lock()))
+    }},
+    {for (T) {}} parking_lot::ReentrantMutex<T> {|self| {
+        Self::new(T::independent_clone(&self.lock()))
+    }},
+}
+
+#[cfg(feature = "spin")]
+speed_or_slower! {
+    ConstantTime;
+    /// # Deadlocks
+    /// Spins forever if the `RwLock` is currently write-locked by the current thread.
+    {for (T) {} R: spin::RelaxStrategy} spin::rwlock::RwLock<T, R> {|self| {
+        Self::new(T::independent_clone(&self.read()))
+    }},
+    /// # Deadlocks
+    /// Spins forever if the current thread already holds the mutex.
+    {for (T) {} R: spin::RelaxStrategy} spin::mutex::Mutex<T, R> {|self| {
+        Self::new(T::independent_clone(&self.lock()))
+    }},
+}
 
 macro_rules! map_and_collect {
     ($($t:ident $({$($where_bounds:tt)*})? $type:ty),* $(,)?) => {
         $(
             #[cfg(feature = "alloc")]
-            impl<$t: IndependentClone<AnySpeed>> IndependentClone<AnySpeed>
+            impl<S, $t: IndependentClone<S>> IndependentClone<S>
             for $type
             where
+                S: AtLeastAsSlowAs<LinearTime>,
                 $($($where_bounds)*)?
             {
                 #[inline]
                 fn independent_clone(&self) -> Self {
+                    trace_clone!("independent", S);
                     self.iter()
                         .map($t::independent_clone)
                         .collect()
@@ -334,20 +834,126 @@ macro_rules! map_and_collect {
 }
 
 map_and_collect! {
-    T alloc::boxed::Box<[T]>,
-    T alloc::vec::Vec<T>,
-    T alloc::collections::VecDeque<T>,
     T alloc::collections::LinkedList<T>,
     T {T: Ord} alloc::collections::BTreeSet<T>,
     T {T: Ord} alloc::collections::BinaryHeap<T>,
 }
 
 #[cfg(feature = "alloc")]
-impl<T: IndependentClone<AnySpeed>> IndependentClone<AnySpeed>
-for core::pin::Pin<alloc::boxed::Box<[T]>>
+impl<S, T> IndependentClone<S> for alloc::boxed::Box<[T]>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    T: IndependentClone<S>,
 {
     #[inline]
     fn independent_clone(&self) -> Self {
+        trace_clone!("independent", S);
+        self.iter()
+            .map(T::independent_clone)
+            .collect()
+    }
+
+    #[inline]
+    fn independent_clone_from(&mut self, source: &Self) {
+        trace_clone!("independent", S);
+        if self.len() == source.len() {
+            for (elem, source_elem) in self.iter_mut().zip(source.iter()) {
+                elem.independent_clone_from(source_elem);
+            }
+        } else {
+            *self = source.independent_clone();
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S, T> IndependentClone<S> for alloc::vec::Vec<T>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    T: IndependentClone<S>,
+{
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        trace_clone!("independent", S);
+        self.iter()
+            .map(T::independent_clone)
+            .collect()
+    }
+
+    #[inline]
+    fn independent_clone_from(&mut self, source: &Self) {
+        trace_clone!("independent", S);
+        self.truncate(source.len());
+        let (init, tail) = source.split_at(self.len());
+
+        for (elem, source_elem) in self.iter_mut().zip(init) {
+            elem.independent_clone_from(source_elem);
+        }
+        self.extend(tail.iter().map(T::independent_clone));
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<S, A> IndependentClone<S> for smallvec::SmallVec<A>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    A: smallvec::Array<Item: IndependentClone<S>>,
+{
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        self.iter()
+            .map(A::Item::independent_clone)
+            .collect()
+    }
+
+    #[inline]
+    fn independent_clone_from(&mut self, source: &Self) {
+        self.truncate(source.len());
+        let (init, tail) = source.split_at(self.len());
+
+        for (elem, source_elem) in self.iter_mut().zip(init) {
+            elem.independent_clone_from(source_elem);
+        }
+        self.extend(tail.iter().map(A::Item::independent_clone));
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S, T> IndependentClone<S> for alloc::collections::VecDeque<T>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    T: IndependentClone<S>,
+{
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        trace_clone!("independent", S);
+        self.iter()
+            .map(T::independent_clone)
+            .collect()
+    }
+
+    #[inline]
+    fn independent_clone_from(&mut self, source: &Self) {
+        trace_clone!("independent", S);
+        self.truncate(source.len());
+        let len = self.len();
+
+        for (elem, source_elem) in self.iter_mut().zip(source) {
+            elem.independent_clone_from(source_elem);
+        }
+        self.extend(source.iter().skip(len).map(T::independent_clone));
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S, T> IndependentClone<S> for core::pin::Pin<alloc::boxed::Box<[T]>>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    T: IndependentClone<S>,
+{
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        trace_clone!("independent", S);
         let new_box = self.iter()
             .map(T::independent_clone)
             .collect::<alloc::boxed::Box<[T]>>();
@@ -357,15 +963,220 @@ for core::pin::Pin<alloc::boxed::Box<[T]>>
 }
 
 #[cfg(feature = "alloc")]
-impl IndependentClone<AnySpeed> for alloc::boxed::Box<str> {
+impl<S: AtLeastAsSlowAs<LinearTime>> IndependentClone<S> for alloc::boxed::Box<str> {
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        trace_clone!("independent", S);
+        self.clone()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: AtLeastAsSlowAs<LinearTime>> IndependentClone<S>
+for core::pin::Pin<alloc::boxed::Box<str>>
+{
     #[inline]
     fn independent_clone(&self) -> Self {
+        trace_clone!("independent", S);
         self.clone()
     }
 }
 
+map_and_collect! {
+    T alloc::rc::Rc<[T]>,
+    T alloc::sync::Arc<[T]>,
+}
+
+/// Implement `IndependentClone<S>` for every speed `S` at least as slow as `LinearTime`, for
+/// reference-counted pointers to unsized string-like types, by copying the contents into a new
+/// allocation.
 #[cfg(feature = "alloc")]
-impl IndependentClone<AnySpeed> for core::pin::Pin<alloc::boxed::Box<str>> {
+macro_rules! shared_str_like {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl<S: AtLeastAsSlowAs<LinearTime>> IndependentClone<S> for $type {
+                #[inline]
+                fn independent_clone(&self) -> Self {
+                    trace_clone!("independent", S);
+                    Self::from(&**self)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "alloc")]
+shared_str_like! {
+    alloc::rc::Rc<str>,
+    alloc::sync::Arc<str>,
+}
+
+#[cfg(feature = "std")]
+shared_str_like! {
+    alloc::rc::Rc<std::path::Path>,
+    alloc::sync::Arc<std::path::Path>,
+}
+
+#[cfg(feature = "camino")]
+shared_str_like! {
+    alloc::rc::Rc<camino::Utf8Path>,
+    alloc::sync::Arc<camino::Utf8Path>,
+}
+
+/// Implement `IndependentClone<LinearTime>` for owned buffer types, which are `NonRecursive`.
+#[cfg(feature = "alloc")]
+macro_rules! owned_buffer {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl IndependentClone<LinearTime> for $type {
+                #[inline]
+                fn independent_clone(&self) -> Self {
+                    trace_clone!("independent", LinearTime);
+                    self.clone()
+                }
+
+                #[inline]
+                fn independent_clone_from(&mut self, source: &Self) {
+                    trace_clone!("independent", LinearTime);
+                    self.clone_from(source);
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "alloc")]
+owned_buffer! {
+    alloc::string::String,
+    alloc::ffi::CString,
+}
+
+/// The clone refers to the same immutable buffer as its source.
+///
+/// # Exceptions
+/// The buffer's reference count is shared mutable state. It can be observed through
+/// `Bytes::is_unique` and `Bytes::try_into_mut`, and through the capacity of a `BytesMut` converted
+/// from a `Bytes`, none of which are subject to the guarantees of `IndependentClone`.
+#[cfg(feature = "bytes")]
+impl IndependentClone<NearInstant> for bytes::Bytes {
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        self.clone()
+    }
+}
+
+/// The contents are copied into a new buffer, whose capacity is the length of the source.
+#[cfg(feature = "bytes")]
+impl IndependentClone<LinearTime> for bytes::BytesMut {
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        self.clone()
+    }
+}
+
+// These strings are immutable, and either stored inline or in a shared buffer.
+#[cfg(feature = "smol_str")]
+non_recursive_near_instant! {
+    smol_str::SmolStr,
+}
+
+// Mutating a shared `EcoString` first copies its contents into a new buffer.
+#[cfg(feature = "ecow")]
+non_recursive_near_instant! {
+    ecow::EcoString,
+}
+
+/// The clone refers to the same immutable buffer as its source.
+///
+/// # Exceptions
+/// The buffer's reference count is shared mutable state. It can be observed through
+/// `ArcStr::strong_count`, which is not subject to the guarantees of `IndependentClone`.
+#[cfg(feature = "arcstr")]
+impl IndependentClone<NearInstant> for arcstr::ArcStr {
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        self.clone()
+    }
+}
+
+/// Strings which are too long to be stored inline are copied into a new buffer.
+#[cfg(feature = "compact_str")]
+impl IndependentClone<LinearTime> for compact_str::CompactString {
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        self.clone()
+    }
+
+    #[inline]
+    fn independent_clone_from(&mut self, source: &Self) {
+        self.clone_from(source);
+    }
+}
+
+/// The digits are copied into a new buffer.
+#[cfg(feature = "bigdecimal")]
+impl IndependentClone<LinearTime> for bigdecimal::BigDecimal {
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        self.clone()
+    }
+
+    #[inline]
+    fn independent_clone_from(&mut self, source: &Self) {
+        self.clone_from(source);
+    }
+}
+
+/// The digits are copied into a new buffer.
+#[cfg(feature = "num")]
+impl IndependentClone<LinearTime> for num_bigint::BigInt {
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        self.clone()
+    }
+
+    #[inline]
+    fn independent_clone_from(&mut self, source: &Self) {
+        self.clone_from(source);
+    }
+}
+
+/// The digits are copied into a new buffer.
+#[cfg(feature = "num")]
+impl IndependentClone<LinearTime> for num_bigint::BigUint {
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        self.clone()
+    }
+
+    #[inline]
+    fn independent_clone_from(&mut self, source: &Self) {
+        self.clone_from(source);
+    }
+}
+
+#[cfg(feature = "std")]
+owned_buffer! {
+    std::ffi::OsString,
+    std::path::PathBuf,
+}
+
+/// The path is copied into a new buffer.
+#[cfg(feature = "camino")]
+impl IndependentClone<LinearTime> for camino::Utf8PathBuf {
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        self.clone()
+    }
+
+    #[inline]
+    fn independent_clone_from(&mut self, source: &Self) {
+        self.clone_from(source);
+    }
+}
+
+#[cfg(feature = "camino")]
+impl<S: AtLeastAsSlowAs<LinearTime>> IndependentClone<S> for alloc::boxed::Box<camino::Utf8Path> {
     #[inline]
     fn independent_clone(&self) -> Self {
         self.clone()
@@ -373,13 +1184,15 @@ impl IndependentClone<AnySpeed> for core::pin::Pin<alloc::boxed::Box<str>> {
 }
 
 #[cfg(feature = "alloc")]
-impl<K, V> IndependentClone<AnySpeed> for alloc::collections::BTreeMap<K, V>
+impl<S, K, V> IndependentClone<S> for alloc::collections::BTreeMap<K, V>
 where
-    K: IndependentClone<AnySpeed> + Ord,
-    V: IndependentClone<AnySpeed>,
+    S: AtLeastAsSlowAs<LinearTime>,
+    K: IndependentClone<S> + Ord,
+    V: IndependentClone<S>,
 {
     #[inline]
     fn independent_clone(&self) -> Self {
+        trace_clone!("independent", S);
         self.iter()
             .map(|(key, val)| {
                 (
@@ -391,38 +1204,275 @@ where
     }
 }
 
+// The hasher is cloned rather than recreated, so that the clone hashes keys the same way as its
+// source (which matters for custom or seeded hashers). `independent_clone_from` is left as the
+// default, as the hasher of an existing collection cannot be replaced.
+#[cfg(feature = "std")]
+impl<S, T, H> IndependentClone<S> for std::collections::HashSet<T, H>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    T: IndependentClone<S> + Eq + core::hash::Hash,
+    H: IndependentClone<S> + core::hash::BuildHasher,
+{
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        trace_clone!("independent", S);
+        let mut set = Self::with_capacity_and_hasher(
+            self.len(),
+            H::independent_clone(self.hasher()),
+        );
+        set.extend(self.iter().map(T::independent_clone));
+        set
+    }
+}
+
 #[cfg(feature = "std")]
-impl<T, S> IndependentClone<AnySpeed> for std::collections::HashSet<T, S>
+impl<S, K, V, H> IndependentClone<S> for std::collections::HashMap<K, V, H>
 where
-    T: IndependentClone<AnySpeed> + Eq + core::hash::Hash,
-    S: core::hash::BuildHasher + Default,
+    S: AtLeastAsSlowAs<LinearTime>,
+    K: IndependentClone<S> + Eq + core::hash::Hash,
+    V: IndependentClone<S>,
+    H: IndependentClone<S> + core::hash::BuildHasher,
+{
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        trace_clone!("independent", S);
+        let mut map = Self::with_capacity_and_hasher(
+            self.len(),
+            H::independent_clone(self.hasher()),
+        );
+        map.extend(self.iter().map(|(key, val)| {
+            (
+                K::independent_clone(key),
+                V::independent_clone(val),
+            )
+        }));
+        map
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<S, T, H> IndependentClone<S> for hashbrown::HashSet<T, H>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    T: IndependentClone<S> + Eq + core::hash::Hash,
+    H: IndependentClone<S> + core::hash::BuildHasher,
+{
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        trace_clone!("independent", S);
+        let mut set = Self::with_capacity_and_hasher(
+            self.len(),
+            H::independent_clone(self.hasher()),
+        );
+        set.extend(self.iter().map(T::independent_clone));
+        set
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<S, K, V, H> IndependentClone<S> for hashbrown::HashMap<K, V, H>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    K: IndependentClone<S> + Eq + core::hash::Hash,
+    V: IndependentClone<S>,
+    H: IndependentClone<S> + core::hash::BuildHasher,
+{
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        trace_clone!("independent", S);
+        let mut map = Self::with_capacity_and_hasher(
+            self.len(),
+            H::independent_clone(self.hasher()),
+        );
+        map.extend(self.iter().map(|(key, val)| {
+            (
+                K::independent_clone(key),
+                V::independent_clone(val),
+            )
+        }));
+        map
+    }
+}
+
+/// The elements are inserted in their current order, so the clone has the same insertion order.
+#[cfg(feature = "indexmap")]
+impl<S, T, H> IndependentClone<S> for indexmap::IndexSet<T, H>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    T: IndependentClone<S> + Eq + core::hash::Hash,
+    H: IndependentClone<S> + core::hash::BuildHasher,
+{
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        let mut set = Self::with_capacity_and_hasher(
+            self.len(),
+            H::independent_clone(self.hasher()),
+        );
+        set.extend(self.iter().map(T::independent_clone));
+        set
+    }
+}
+
+/// The entries are inserted in their current order, so the clone has the same insertion order.
+#[cfg(feature = "indexmap")]
+impl<S, K, V, H> IndependentClone<S> for indexmap::IndexMap<K, V, H>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    K: IndependentClone<S> + Eq + core::hash::Hash,
+    V: IndependentClone<S>,
+    H: IndependentClone<S> + core::hash::BuildHasher,
+{
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        let mut map = Self::with_capacity_and_hasher(
+            self.len(),
+            H::independent_clone(self.hasher()),
+        );
+        map.extend(self.iter().map(|(key, val)| {
+            (
+                K::independent_clone(key),
+                V::independent_clone(val),
+            )
+        }));
+        map
+    }
+}
+
+/// The shards are read-locked one at a time, so if the map is concurrently modified, the clone
+/// need not reflect any single state of the map.
+///
+/// # Deadlocks
+/// May deadlock if the current thread holds any sort of reference into the map.
+#[cfg(feature = "dashmap")]
+impl<S, K, V, H> IndependentClone<S> for dashmap::DashMap<K, V, H>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    K: IndependentClone<S> + Eq + core::hash::Hash,
+    V: IndependentClone<S>,
+    H: IndependentClone<S> + core::hash::BuildHasher + Clone,
+{
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        let mut map = Self::with_capacity_and_hasher(
+            self.len(),
+            H::independent_clone(self.hasher()),
+        );
+        map.extend(self.iter().map(|entry| {
+            (
+                K::independent_clone(entry.key()),
+                V::independent_clone(entry.value()),
+            )
+        }));
+        map
+    }
+}
+
+/// The hasher is recreated with `Default`, as `dashmap` provides no way to access the hasher of a
+/// `DashSet`.
+///
+/// The shards are read-locked one at a time, so if the set is concurrently modified, the clone
+/// need not reflect any single state of the set.
+///
+/// # Deadlocks
+/// May deadlock if the current thread holds any sort of reference into the set.
+#[cfg(feature = "dashmap")]
+impl<S, T, H> IndependentClone<S> for dashmap::DashSet<T, H>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    T: IndependentClone<S> + Eq + core::hash::Hash,
+    H: core::hash::BuildHasher + Clone + Default,
+{
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        let mut set = Self::with_capacity_and_hasher(self.len(), H::default());
+        set.extend(self.iter().map(|entry| T::independent_clone(entry.key())));
+        set
+    }
+}
+
+// TODO: other boxed things
+
+#[cfg(feature = "heapless")]
+speed_or_slower! {
+    LinearTime;
+    {for (T) {} LenT: heapless::LenType, const N: usize} heapless::Vec<T, N, LenT> {|self| {
+        self.iter().map(T::independent_clone).collect()
+    }},
+    {for (T) {} const N: usize} heapless::Deque<T, N> {|self| {
+        let mut deque = Self::new();
+        deque.extend(self.iter().map(T::independent_clone));
+        deque
+    }},
+    /// The hasher is recreated with `Default`, as `heapless` provides no way to build an
+    /// `IndexMap` with a given hasher. This is exact for stateless hashers, like the one used by
+    /// `FnvIndexMap`.
+    {for (K V) {
+        K: Eq + core::hash::Hash,
+        H: core::hash::BuildHasher + Default,
+    } H, const N: usize} heapless::IndexMap<K, V, H, N> {|self| {
+        let mut map = Self::default();
+        map.extend(self.iter().map(|(key, val)| {
+            (
+                K::independent_clone(key),
+                V::independent_clone(val),
+            )
+        }));
+        map
+    }},
+}
+
+// The capacity is a constant, so these take constant time (for a given `CAP`).
+#[cfg(feature = "arrayvec")]
+speed_or_slower! {
+    ConstantTime;
+    {for (T) {} const CAP: usize} arrayvec::ArrayVec<T, CAP> {|self| {
+        self.iter().map(T::independent_clone).collect()
+    }},
+    {for () {} const CAP: usize} arrayvec::ArrayString<CAP> {|self| {
+        *self
+    }},
+}
+
+#[cfg(feature = "tinyvec")]
+impl<S, A> IndependentClone<S> for tinyvec::ArrayVec<A>
+where
+    S: AtLeastAsSlowAs<ConstantTime>,
+    A: tinyvec::Array<Item: IndependentClone<S>>,
 {
     #[inline]
     fn independent_clone(&self) -> Self {
         self.iter()
-            .map(T::independent_clone)
+            .map(A::Item::independent_clone)
             .collect()
     }
 }
 
-#[cfg(feature = "std")]
-impl<K, V, S> IndependentClone<AnySpeed> for std::collections::HashMap<K, V, S>
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<S, A> IndependentClone<S> for tinyvec::TinyVec<A>
 where
-    K: IndependentClone<AnySpeed> + Eq + core::hash::Hash,
-    V: IndependentClone<AnySpeed>,
-    S: core::hash::BuildHasher + Default,
+    S: AtLeastAsSlowAs<LinearTime>,
+    A: tinyvec::Array<Item: IndependentClone<S>>,
 {
     #[inline]
     fn independent_clone(&self) -> Self {
         self.iter()
-            .map(|(key, val)| {
-                (
-                    K::independent_clone(key),
-                    V::independent_clone(val),
-                )
-            })
+            .map(A::Item::independent_clone)
             .collect()
     }
 }
 
-// TODO: iterators, other boxed things
+#[cfg(feature = "heapless")]
+impl<LenT: heapless::LenType, const N: usize> IndependentClone<LinearTime>
+for heapless::String<N, LenT>
+{
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        self.clone()
+    }
+
+    #[inline]
+    fn independent_clone_from(&mut self, source: &Self) {
+        self.clone_from(source);
+    }
+}
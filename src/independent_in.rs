@@ -0,0 +1,206 @@
+#![expect(clippy::absolute_paths, reason = "there's a lot of random types used")]
+#![warn(clippy::missing_inline_in_public_items, reason = "almost everything is very short")]
+
+use core::alloc::Allocator;
+
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::independent::IndependentClone;
+use crate::speed::{Speed, AtLeastAsSlowAs, ConstantTime, LinearTime};
+
+
+/// Get deep clones of a value, which do not share any semantically-important mutable state, and
+/// whose allocations are made in a given allocator.
+///
+/// This is the allocator-aware counterpart of [`IndependentClone`], allowing deep clones to be
+/// placed in arenas or custom allocators. The clone is of type [`Output`](Self::Output), which is
+/// usually `Self` with its allocator replaced by `A`. Only the allocations made by the value
+/// itself are made in `alloc`; for instance, the elements of a `Vec<Vec<T>>` are cloned with
+/// [`IndependentClone`], in the global allocator.
+///
+/// An independent clone returned by this trait must satisfy the same guarantees as one from
+/// [`IndependentClone`]; read its documentation for more.
+pub trait IndependentCloneIn<S: Speed, A: Allocator> {
+    /// The type of an independent clone placed in an allocator of type `A`.
+    type Output;
+
+    /// Get a deep clone of a value, which does not share any semantically-important mutable
+    /// state, and whose allocations are made in `alloc`.
+    ///
+    /// Read [`IndependentCloneIn`] for more.
+    #[must_use]
+    fn independent_clone_in(&self, alloc: A) -> Self::Output;
+}
+
+
+impl<S, T, A, B> IndependentCloneIn<S, A> for Box<T, B>
+where
+    S: AtLeastAsSlowAs<ConstantTime>,
+    T: IndependentClone<S>,
+    A: Allocator,
+    B: Allocator,
+{
+    type Output = Box<T, A>;
+
+    #[inline]
+    fn independent_clone_in(&self, alloc: A) -> Self::Output {
+        Box::new_in(T::independent_clone(self), alloc)
+    }
+}
+
+impl<S, T, A, B> IndependentCloneIn<S, A> for Box<[T], B>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    T: IndependentClone<S>,
+    A: Allocator,
+    B: Allocator,
+{
+    type Output = Box<[T], A>;
+
+    #[inline]
+    fn independent_clone_in(&self, alloc: A) -> Self::Output {
+        let mut vec = Vec::with_capacity_in(self.len(), alloc);
+        vec.extend(self.iter().map(T::independent_clone));
+        vec.into_boxed_slice()
+    }
+}
+
+impl<S, T, A, B> IndependentCloneIn<S, A> for Vec<T, B>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    T: IndependentClone<S>,
+    A: Allocator,
+    B: Allocator,
+{
+    type Output = Vec<T, A>;
+
+    #[inline]
+    fn independent_clone_in(&self, alloc: A) -> Self::Output {
+        let mut vec = Vec::with_capacity_in(self.len(), alloc);
+        vec.extend(self.iter().map(T::independent_clone));
+        vec
+    }
+}
+
+impl<S, T, A, B> IndependentCloneIn<S, A> for alloc::collections::VecDeque<T, B>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    T: IndependentClone<S>,
+    A: Allocator,
+    B: Allocator,
+{
+    type Output = alloc::collections::VecDeque<T, A>;
+
+    #[inline]
+    fn independent_clone_in(&self, alloc: A) -> Self::Output {
+        let mut deque = alloc::collections::VecDeque::with_capacity_in(self.len(), alloc);
+        deque.extend(self.iter().map(T::independent_clone));
+        deque
+    }
+}
+
+impl<S, T, A, B> IndependentCloneIn<S, A> for alloc::collections::LinkedList<T, B>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    T: IndependentClone<S>,
+    A: Allocator,
+    B: Allocator,
+{
+    type Output = alloc::collections::LinkedList<T, A>;
+
+    #[inline]
+    fn independent_clone_in(&self, alloc: A) -> Self::Output {
+        let mut list = alloc::collections::LinkedList::new_in(alloc);
+        for elem in self {
+            list.push_back(T::independent_clone(elem));
+        }
+        list
+    }
+}
+
+impl<S, T, A, B> IndependentCloneIn<S, A> for alloc::collections::BinaryHeap<T, B>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    T: IndependentClone<S> + Ord,
+    A: Allocator,
+    B: Allocator,
+{
+    type Output = alloc::collections::BinaryHeap<T, A>;
+
+    #[inline]
+    fn independent_clone_in(&self, alloc: A) -> Self::Output {
+        let mut heap = alloc::collections::BinaryHeap::with_capacity_in(self.len(), alloc);
+        heap.extend(self.iter().map(T::independent_clone));
+        heap
+    }
+}
+
+impl<S, T, A, B> IndependentCloneIn<S, A> for alloc::collections::BTreeSet<T, B>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    T: IndependentClone<S> + Ord,
+    A: Allocator + Clone,
+    B: Allocator + Clone,
+{
+    type Output = alloc::collections::BTreeSet<T, A>;
+
+    #[inline]
+    fn independent_clone_in(&self, alloc: A) -> Self::Output {
+        let mut set = alloc::collections::BTreeSet::new_in(alloc);
+        set.extend(self.iter().map(T::independent_clone));
+        set
+    }
+}
+
+impl<S, K, V, A, B> IndependentCloneIn<S, A> for alloc::collections::BTreeMap<K, V, B>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    K: IndependentClone<S> + Ord,
+    V: IndependentClone<S>,
+    A: Allocator + Clone,
+    B: Allocator + Clone,
+{
+    type Output = alloc::collections::BTreeMap<K, V, A>;
+
+    #[inline]
+    fn independent_clone_in(&self, alloc: A) -> Self::Output {
+        let mut map = alloc::collections::BTreeMap::new_in(alloc);
+        map.extend(self.iter().map(|(key, val)| {
+            (
+                K::independent_clone(key),
+                V::independent_clone(val),
+            )
+        }));
+        map
+    }
+}
+
+impl<S, T, A, B> IndependentCloneIn<S, A> for alloc::rc::Rc<T, B>
+where
+    S: AtLeastAsSlowAs<ConstantTime>,
+    T: IndependentClone<S>,
+    A: Allocator,
+    B: Allocator,
+{
+    type Output = alloc::rc::Rc<T, A>;
+
+    #[inline]
+    fn independent_clone_in(&self, alloc: A) -> Self::Output {
+        alloc::rc::Rc::new_in(T::independent_clone(self), alloc)
+    }
+}
+
+impl<S, T, A, B> IndependentCloneIn<S, A> for alloc::sync::Arc<T, B>
+where
+    S: AtLeastAsSlowAs<ConstantTime>,
+    T: IndependentClone<S>,
+    A: Allocator,
+    B: Allocator,
+{
+    type Output = alloc::sync::Arc<T, A>;
+
+    #[inline]
+    fn independent_clone_in(&self, alloc: A) -> Self::Output {
+        alloc::sync::Arc::new_in(T::independent_clone(self), alloc)
+    }
+}
@@ -0,0 +1,139 @@
+use core::{iter::FusedIterator, marker::PhantomData};
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+
+use crate::{independent::IndependentClone, mirrored::MirroredClone, speed::Speed};
+
+
+/// Adapters for iterators over references, analogous to [`Iterator::cloned`].
+///
+/// ```
+/// use clone_behavior::{IteratorCloneExt as _, LinearTime};
+///
+/// let rows = [vec![1_u8], vec![2, 3]];
+/// let copies: Vec<Vec<u8>> = rows.iter().independent_cloned::<LinearTime>().collect();
+/// assert_eq!(copies, rows);
+/// ```
+pub trait IteratorCloneExt<'a, T: 'a>: Iterator<Item = &'a T> + Sized {
+    /// Create an iterator which yields an [independent clone] at speed `S` of each element.
+    ///
+    /// [independent clone]: IndependentClone
+    #[inline]
+    fn independent_cloned<S>(self) -> IndependentCloned<Self, S>
+    where
+        S: Speed,
+        T: IndependentClone<S>,
+    {
+        IndependentCloned {
+            iter:   self,
+            _speed: PhantomData,
+        }
+    }
+
+    /// Create an iterator which yields a [mirrored clone] at speed `S` of each element.
+    ///
+    /// [mirrored clone]: MirroredClone
+    #[inline]
+    fn mirrored_cloned<S>(self) -> MirroredCloned<Self, S>
+    where
+        S: Speed,
+        T: MirroredClone<S>,
+    {
+        MirroredCloned {
+            iter:   self,
+            _speed: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: 'a, I: Iterator<Item = &'a T>> IteratorCloneExt<'a, T> for I {}
+
+/// Implement an iterator adapter which maps each element with a cloning function.
+macro_rules! cloned_adapter {
+    ($(#[$meta:meta])* $name:ident, $clone_tr:ident, $clone_fn:ident) => {
+        $(#[$meta])*
+        #[must_use = "iterators are lazy and do nothing unless consumed"]
+        pub struct $name<I, S> {
+            iter:   I,
+            _speed: PhantomData<S>,
+        }
+
+        impl<'a, T, I, S> Iterator for $name<I, S>
+        where
+            T: $clone_tr<S> + 'a,
+            I: Iterator<Item = &'a T>,
+            S: Speed,
+        {
+            type Item = T;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.iter.next().map(T::$clone_fn)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.iter.size_hint()
+            }
+        }
+
+        impl<'a, T, I, S> DoubleEndedIterator for $name<I, S>
+        where
+            T: $clone_tr<S> + 'a,
+            I: DoubleEndedIterator<Item = &'a T>,
+            S: Speed,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.iter.next_back().map(T::$clone_fn)
+            }
+        }
+
+        impl<'a, T, I, S> ExactSizeIterator for $name<I, S>
+        where
+            T: $clone_tr<S> + 'a,
+            I: ExactSizeIterator<Item = &'a T>,
+            S: Speed,
+        {}
+
+        impl<'a, T, I, S> FusedIterator for $name<I, S>
+        where
+            T: $clone_tr<S> + 'a,
+            I: FusedIterator<Item = &'a T>,
+            S: Speed,
+        {}
+
+        impl<I: Clone, S> Clone for $name<I, S> {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self {
+                    iter:   self.iter.clone(),
+                    _speed: PhantomData,
+                }
+            }
+        }
+
+        impl<I: Debug, S> Debug for $name<I, S> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                f.debug_struct(stringify!($name)).field("iter", &self.iter).finish()
+            }
+        }
+    };
+}
+
+cloned_adapter!(
+    /// An iterator which yields an [independent clone] at speed `S` of each element of `I`.
+    ///
+    /// Created by [`IteratorCloneExt::independent_cloned`].
+    ///
+    /// [independent clone]: IndependentClone
+    IndependentCloned, IndependentClone, independent_clone
+);
+
+cloned_adapter!(
+    /// An iterator which yields a [mirrored clone] at speed `S` of each element of `I`.
+    ///
+    /// Created by [`IteratorCloneExt::mirrored_cloned`].
+    ///
+    /// [mirrored clone]: MirroredClone
+    MirroredCloned, MirroredClone, mirrored_clone
+);
@@ -12,11 +12,14 @@
 #![expect(
     missing_copy_implementations,
     missing_debug_implementations,
-    reason = "The four uninhabited Speed types are what trigger these lints",
+    reason = "The uninhabited Speed and CloneMode types are what trigger these lints",
 )]
 
 #![no_std]
 
+#![cfg_attr(feature = "allocator_api", feature(allocator_api, btreemap_alloc))]
+#![cfg_attr(feature = "exclusive_wrapper", feature(exclusive_wrapper))]
+
 #[cfg(feature = "std")]
 extern crate std;
 
@@ -25,21 +28,99 @@ extern crate alloc;
 
 
 mod speed;
+mod mode;
 
 mod independent;
 mod mirrored;
 mod mixed;
+mod immutable;
+mod stateless;
+mod trusted;
+
+#[cfg(feature = "alloc")]
+mod boxed;
+#[cfg(feature = "allocator_api")]
+mod independent_in;
+#[cfg(feature = "bumpalo")]
+mod bump;
+
+mod try_independent;
+mod try_mirrored;
+mod async_clone;
+mod snapshot;
+mod shared_parts;
 
 mod blanket_impls;
+mod behavior;
+mod ext;
+mod iter;
+mod wrappers;
+mod cells;
+mod macros;
+
+pub mod fns;
+#[cfg(feature = "bench")]
+pub mod bench;
+#[cfg(feature = "proptest")]
+mod property;
+#[cfg(feature = "verify")]
+pub mod verify;
 
 
 pub use self::{
+    async_clone::{AsyncIndependentClone, AsyncMirroredClone},
+    behavior::CloneBehavior,
     blanket_impls::NonRecursive,
+    ext::CloneBehaviorExt,
+    immutable::Immutable,
+    iter::{IndependentCloned, IteratorCloneExt, MirroredCloned},
     independent::IndependentClone,
     mirrored::MirroredClone,
     mixed::MixedClone,
+    shared_parts::SharedParts,
+    snapshot::Snapshot,
+    stateless::Stateless,
+    trusted::TrustedIndependent,
+    try_independent::TryIndependentClone,
+    try_mirrored::TryMirroredClone,
+};
+pub use self::wrappers::{Independent, WithSpeed};
+#[cfg(feature = "alloc")]
+pub use self::wrappers::LocalShared;
+#[cfg(feature = "std")]
+pub use self::wrappers::Shared;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use self::wrappers::{CloneCounts, Counted, LazyIndependent};
+pub use self::cells::CloneCell;
+pub use self::speed::{Speed, AtLeastAsSlowAs, SpeedClass, SpeedMax, Max};
+pub use self::speed::{NearInstant, ConstantTime, LogTime, LinearTime, AnySpeed};
+pub use self::speed::{Amortized, NonBlocking, CustomSpeed, SpeedTag};
+pub use self::mode::{CloneMode, IndependentMode, MirroredMode, MixedMode};
+
+#[cfg(feature = "alloc")]
+pub use self::boxed::{
+    independent_clone_box, mirrored_clone_box, IndependentCloneBoxed, MirroredCloneBoxed,
 };
-pub use self::speed::{Speed, NearInstant, ConstantTime, LogTime, AnySpeed};
+#[cfg(feature = "allocator_api")]
+pub use self::independent_in::IndependentCloneIn;
+#[cfg(feature = "bumpalo")]
+pub use self::bump::IndependentCloneInBump;
+
+#[cfg(feature = "derive")]
+pub use clone_behavior_derive::{IndependentClone, MirroredClone, MixedClone};
+
+/// Items used by this crate's macros.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::boxed::Box;
+    #[cfg(feature = "dyn-clone")]
+    pub use dyn_clone;
+    #[cfg(feature = "proptest")]
+    pub use proptest;
+    #[cfg(feature = "proptest")]
+    pub use crate::property::{check_independent, check_mirrored};
+}
 
 
 macro_rules! call_varargs_macro {
@@ -60,3 +141,24 @@ macro_rules! call_varargs_macro {
 }
 
 pub(crate) use call_varargs_macro;
+
+/// Emit a `tracing` event recording that a clone of `Self` was performed in the given mode, at
+/// speed `$speed`. Does nothing unless the `tracing` feature is enabled.
+///
+/// No event is emitted at [nonblocking](Speed::NON_BLOCKING) speeds, since a `tracing` subscriber
+/// may acquire locks or allocate.
+macro_rules! trace_clone {
+    ($mode:literal, $speed:ty) => {
+        #[cfg(feature = "tracing")]
+        if !<$speed as $crate::Speed>::NON_BLOCKING {
+            ::tracing::trace!(
+                type_name = ::core::any::type_name::<Self>(),
+                mode = $mode,
+                speed = ::core::any::type_name::<$speed>(),
+                "performed a clone",
+            );
+        }
+    };
+}
+
+pub(crate) use trace_clone;
@@ -0,0 +1,307 @@
+/// Implement [`NonRecursive`], and each of [`IndependentClone`] and [`MixedClone`] at
+/// [`NearInstant`] speed, for the given `Copy` types.
+///
+/// This is intended for plain data types whose copies share no semantically-important mutable
+/// state, such as a struct of integers. The implementations simply copy the value. Since the types
+/// are `NonRecursive`, every slower speed is provided as well.
+///
+/// Generic types can be given with their generic parameters in a leading `{for ..}` block, as in
+/// `impl_for_copy!({for T: Copy} Point<T>)`; note that the type must not be recursive in its
+/// generics.
+///
+/// ```
+/// use clone_behavior::{impl_for_copy, AnySpeed, IndependentClone, MixedClone};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct Point {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// struct Rgb(u8, u8, u8);
+///
+/// impl_for_copy!(Point, Rgb);
+///
+/// let point = Point { x: 1., y: 2. };
+/// assert_eq!(IndependentClone::<AnySpeed>::independent_clone(&point), point);
+/// assert_eq!(MixedClone::<AnySpeed>::mixed_clone(&Rgb(1, 2, 3)), Rgb(1, 2, 3));
+/// ```
+///
+/// [`NonRecursive`]: crate::NonRecursive
+/// [`IndependentClone`]: crate::IndependentClone
+/// [`MixedClone`]: crate::MixedClone
+/// [`NearInstant`]: crate::NearInstant
+#[macro_export]
+macro_rules! impl_for_copy {
+    ($($({for $($bounds:tt)+})? $type:ty),* $(,)?) => {
+        $(
+            impl<$($($bounds)+)?> $crate::NonRecursive for $type {}
+
+            impl<$($($bounds)+)?> $crate::IndependentClone<$crate::NearInstant> for $type {
+                #[inline]
+                fn independent_clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl<$($($bounds)+)?> $crate::MixedClone<$crate::NearInstant> for $type {
+                #[inline]
+                fn mixed_clone(&self) -> Self {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+/// Implement each cloning trait of this crate for a newtype, at every speed for which its wrapped
+/// field implements the trait, by forwarding to that field.
+///
+/// The field is given by name (or index, for a tuple struct) along with its type, as in
+/// `forward_newtype!(Handle => 0: Arc<State>)` or `forward_newtype!(Config => inner: Settings)`;
+/// the newtype must have no other fields. Generic types can be given with their generic parameters
+/// in a leading `{for ..}` block, as in `forward_newtype!({for T} Handle<T> => 0: Arc<T>)`.
+///
+/// The traits forwarded are [`IndependentClone`] (including `independent_clone_from`),
+/// [`MirroredClone`], [`MixedClone`], [`TryIndependentClone`], [`TryMirroredClone`],
+/// [`AsyncIndependentClone`], and [`AsyncMirroredClone`].
+///
+/// The newtype is not made [`NonRecursive`], as the forwarded implementations already cover
+/// every speed, and would overlap with the blanket implementations for `NonRecursive` types.
+///
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use clone_behavior::{forward_newtype, ConstantTime, MirroredClone};
+///
+/// #[derive(Debug)]
+/// struct Counter(Arc<Mutex<u32>>);
+///
+/// forward_newtype!(Counter => 0: Arc<Mutex<u32>>);
+///
+/// let counter = Counter(Arc::new(Mutex::new(0)));
+/// let mirror = MirroredClone::<ConstantTime>::mirrored_clone(&counter);
+/// *mirror.0.lock().unwrap() += 1;
+/// assert_eq!(*counter.0.lock().unwrap(), 1);
+/// ```
+///
+/// [`IndependentClone`]: crate::IndependentClone
+/// [`MirroredClone`]: crate::MirroredClone
+/// [`MixedClone`]: crate::MixedClone
+/// [`TryIndependentClone`]: crate::TryIndependentClone
+/// [`TryMirroredClone`]: crate::TryMirroredClone
+/// [`AsyncIndependentClone`]: crate::AsyncIndependentClone
+/// [`AsyncMirroredClone`]: crate::AsyncMirroredClone
+/// [`NonRecursive`]: crate::NonRecursive
+#[macro_export]
+macro_rules! forward_newtype {
+    ($($({for $($bounds:tt)+})? $type:ty => $field:tt: $inner:ty),* $(,)?) => {
+        $(
+            impl<$($($bounds)+,)? __S> $crate::IndependentClone<__S> for $type
+            where
+                __S: $crate::Speed,
+                $inner: $crate::IndependentClone<__S>,
+            {
+                #[inline]
+                fn independent_clone(&self) -> Self {
+                    Self {
+                        $field: <$inner as $crate::IndependentClone<__S>>::independent_clone(
+                            &self.$field,
+                        ),
+                    }
+                }
+
+                #[inline]
+                fn independent_clone_from(&mut self, source: &Self) {
+                    <$inner as $crate::IndependentClone<__S>>::independent_clone_from(
+                        &mut self.$field,
+                        &source.$field,
+                    );
+                }
+            }
+
+            impl<$($($bounds)+,)? __S> $crate::MirroredClone<__S> for $type
+            where
+                __S: $crate::Speed,
+                $inner: $crate::MirroredClone<__S>,
+            {
+                #[inline]
+                fn mirrored_clone(&self) -> Self {
+                    Self {
+                        $field: <$inner as $crate::MirroredClone<__S>>::mirrored_clone(
+                            &self.$field,
+                        ),
+                    }
+                }
+            }
+
+            impl<$($($bounds)+,)? __S> $crate::MixedClone<__S> for $type
+            where
+                __S: $crate::Speed,
+                $inner: $crate::MixedClone<__S>,
+            {
+                #[inline]
+                fn mixed_clone(&self) -> Self {
+                    Self {
+                        $field: <$inner as $crate::MixedClone<__S>>::mixed_clone(&self.$field),
+                    }
+                }
+            }
+
+            impl<$($($bounds)+,)? __S> $crate::TryIndependentClone<__S> for $type
+            where
+                __S: $crate::Speed,
+                $inner: $crate::TryIndependentClone<__S>,
+            {
+                type Error = <$inner as $crate::TryIndependentClone<__S>>::Error;
+
+                #[inline]
+                fn try_independent_clone(&self) -> ::core::result::Result<Self, Self::Error> {
+                    ::core::result::Result::Ok(Self {
+                        $field: <$inner as $crate::TryIndependentClone<__S>>::try_independent_clone(
+                            &self.$field,
+                        )?,
+                    })
+                }
+            }
+
+            impl<$($($bounds)+,)? __S> $crate::TryMirroredClone<__S> for $type
+            where
+                __S: $crate::Speed,
+                $inner: $crate::TryMirroredClone<__S>,
+            {
+                type Error = <$inner as $crate::TryMirroredClone<__S>>::Error;
+
+                #[inline]
+                fn try_mirrored_clone(&self) -> ::core::result::Result<Self, Self::Error> {
+                    ::core::result::Result::Ok(Self {
+                        $field: <$inner as $crate::TryMirroredClone<__S>>::try_mirrored_clone(
+                            &self.$field,
+                        )?,
+                    })
+                }
+            }
+
+            impl<$($($bounds)+,)? __S> $crate::AsyncIndependentClone<__S> for $type
+            where
+                __S: $crate::Speed,
+                $inner: $crate::AsyncIndependentClone<__S>,
+            {
+                #[inline]
+                async fn async_independent_clone(&self) -> Self {
+                    Self {
+                        $field: <$inner as $crate::AsyncIndependentClone<__S>>
+                            ::async_independent_clone(&self.$field)
+                            .await,
+                    }
+                }
+            }
+
+            impl<$($($bounds)+,)? __S> $crate::AsyncMirroredClone<__S> for $type
+            where
+                __S: $crate::Speed,
+                $inner: $crate::AsyncMirroredClone<__S>,
+            {
+                #[inline]
+                async fn async_mirrored_clone(&self) -> Self {
+                    Self {
+                        $field: <$inner as $crate::AsyncMirroredClone<__S>>
+                            ::async_mirrored_clone(&self.$field)
+                            .await,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+/// Implement [`IndependentClone`] for a type at the given speed (and every speed which is
+/// [`AtLeastAsSlowAs`] it), by delegating to its [`Clone`] implementation.
+///
+/// This asserts that `Clone::clone` already performs an independent clone of the type, in the
+/// given amount of time; it is up to the caller to verify that. Generic types can be given with
+/// their generic parameters in a leading `{for ..}` block, as in
+/// `clone_is_independent!({for T: Clone} Grid<T>, LinearTime)`.
+///
+/// Note that the orphan rule only permits this macro to be used on types defined in the current
+/// crate.
+///
+/// ```
+/// use clone_behavior::{clone_is_independent, AnySpeed, IndependentClone, LinearTime};
+///
+/// #[derive(Debug, Clone, PartialEq, Eq)]
+/// struct Document {
+///     paragraphs: Vec<String>,
+/// }
+///
+/// clone_is_independent!(Document, LinearTime);
+///
+/// let doc = Document { paragraphs: vec!["Hello".to_owned()] };
+/// assert_eq!(IndependentClone::<AnySpeed>::independent_clone(&doc), doc);
+/// ```
+///
+/// [`IndependentClone`]: crate::IndependentClone
+/// [`AtLeastAsSlowAs`]: crate::AtLeastAsSlowAs
+#[macro_export]
+macro_rules! clone_is_independent {
+    ($({for $($bounds:tt)+})? $type:ty, $speed:ty $(,)?) => {
+        impl<$($($bounds)+,)? __S> $crate::IndependentClone<__S> for $type
+        where
+            __S: $crate::AtLeastAsSlowAs<$speed>,
+        {
+            #[inline]
+            fn independent_clone(&self) -> Self {
+                ::core::clone::Clone::clone(self)
+            }
+
+            #[inline]
+            fn independent_clone_from(&mut self, source: &Self) {
+                ::core::clone::Clone::clone_from(self, source);
+            }
+        }
+    };
+}
+
+/// Implement [`MirroredClone`] for a type at the given speed (and every speed which is
+/// [`AtLeastAsSlowAs`] it), by delegating to its [`Clone`] implementation.
+///
+/// This asserts that `Clone::clone` already performs a mirrored clone of the type, in the given
+/// amount of time; it is up to the caller to verify that. Generic types can be given with their
+/// generic parameters in a leading `{for ..}` block, as in
+/// `clone_is_mirrored!({for T} Handle<T>, ConstantTime)`.
+///
+/// Note that the orphan rule only permits this macro to be used on types defined in the current
+/// crate.
+///
+/// ```
+/// use std::rc::Rc;
+/// use clone_behavior::{clone_is_mirrored, ConstantTime, MirroredClone};
+///
+/// #[derive(Clone)]
+/// struct Handle {
+///     state: Rc<str>,
+/// }
+///
+/// clone_is_mirrored!(Handle, ConstantTime);
+///
+/// let handle = Handle { state: Rc::from("shared") };
+/// let mirror = MirroredClone::<ConstantTime>::mirrored_clone(&handle);
+/// assert!(Rc::ptr_eq(&handle.state, &mirror.state));
+/// ```
+///
+/// [`MirroredClone`]: crate::MirroredClone
+/// [`AtLeastAsSlowAs`]: crate::AtLeastAsSlowAs
+#[macro_export]
+macro_rules! clone_is_mirrored {
+    ($({for $($bounds:tt)+})? $type:ty, $speed:ty $(,)?) => {
+        impl<$($($bounds)+,)? __S> $crate::MirroredClone<__S> for $type
+        where
+            __S: $crate::AtLeastAsSlowAs<$speed>,
+        {
+            #[inline]
+            fn mirrored_clone(&self) -> Self {
+                ::core::clone::Clone::clone(self)
+            }
+        }
+    };
+}
@@ -2,7 +2,11 @@
 #![warn(clippy::missing_inline_in_public_items, reason = "almost everything is very short")]
 
 use crate::call_varargs_macro;
-use crate::speed::{Speed, NearInstant, ConstantTime, LogTime, AnySpeed};
+use crate::speed::{Speed, AtLeastAsSlowAs, NearInstant, ConstantTime, NonBlocking};
+#[cfg(feature = "alloc")]
+use crate::speed::LinearTime;
+#[cfg(feature = "std")]
+use crate::independent::IndependentClone;
 
 
 /// Get clones that share all semantically-important mutable state.
@@ -32,8 +36,16 @@ use crate::speed::{Speed, NearInstant, ConstantTime, LogTime, AnySpeed};
 /// - TLDR of above bullet: users should not assume that references returned from different mirrored
 ///   clones refer to the same value, only that those values *behave* the same.
 ///
+/// # `Box<T>`
+/// There is no generic implementation for `Box<T>`, for the same coherence reasons described for
+/// [`IndependentClone`](crate::IndependentClone#boxt). A crate may implement `MirroredClone` for
+/// `Box<TheirType>` itself, as [`impl_for_box!`] does, or use [`mirrored_clone_box`] to get a
+/// mirrored clone of any `T: MirroredClone<S>` in a new box.
+///
 /// [`Rc`]: std::rc::Rc
 /// [`Arc`]: std::sync::Arc
+/// [`impl_for_box!`]: crate::impl_for_box
+/// [`mirrored_clone_box`]: crate::mirrored_clone_box
 pub trait MirroredClone<S: Speed>: Sized {
     /// Get a clone that shares all semantically-important mutable state with its source.
     ///
@@ -60,13 +72,159 @@ macro_rules! non_recursive_near_instant {
     };
 }
 
+// A copied reference or pointer refers to the same object as its source, so mutations made through
+// one (if any are possible) are visible through the other.
 non_recursive_near_instant! {
     (),
+    {for T: ?Sized} &T,
+    {for T: ?Sized} *const T,
+    {for T: ?Sized} *mut T,
     core::convert::Infallible,
     {for T} core::iter::Empty<T>,
     {for T: ?Sized} core::marker::PhantomData<T>,
     core::marker::PhantomPinned,
     core::ops::RangeFull,
+    {for T: ?Sized} core::ptr::NonNull<T>,
+}
+
+#[cfg(feature = "bytes")]
+non_recursive_near_instant! {
+    bytes::Bytes,
+}
+
+// Cloned senders and receivers are handles to the same channel.
+#[cfg(feature = "crossbeam-channel")]
+non_recursive_near_instant! {
+    {for T} crossbeam_channel::Sender<T>,
+    {for T} crossbeam_channel::Receiver<T>,
+}
+
+#[cfg(feature = "flume")]
+non_recursive_near_instant! {
+    {for T} flume::Sender<T>,
+    {for T} flume::Receiver<T>,
+}
+
+#[cfg(feature = "async-channel")]
+non_recursive_near_instant! {
+    {for T} async_channel::Sender<T>,
+    {for T} async_channel::Receiver<T>,
+}
+
+// A `watch::Receiver` or `broadcast::Receiver` also tracks which values it has seen, so those only
+// implement `MixedClone`.
+#[cfg(feature = "tokio")]
+non_recursive_near_instant! {
+    {for T} tokio::sync::broadcast::Sender<T>,
+    {for T} tokio::sync::mpsc::Sender<T>,
+    {for T} tokio::sync::mpsc::UnboundedSender<T>,
+    {for T} tokio::sync::mpsc::WeakSender<T>,
+    {for T} tokio::sync::mpsc::WeakUnboundedSender<T>,
+    {for T} tokio::sync::watch::Sender<T>,
+}
+
+// A bounded `mpsc::Sender` has its own guaranteed slot in the channel, and can be parked
+// independently of other senders, so it only implements `MixedClone`. Every clone of a `Shared`
+// future resolves to (a clone of) the same output.
+#[cfg(feature = "futures")]
+non_recursive_near_instant! {
+    {for T} futures::channel::mpsc::UnboundedSender<T>,
+    {for Fut: Future} futures::future::Shared<Fut>,
+}
+
+#[cfg(feature = "std")]
+#[allow(
+    clippy::std_instead_of_core,
+    reason = "`core::io` is unstable, and only nightly Clippy suggests it",
+)]
+const _: () = {
+    non_recursive_near_instant! {
+        std::io::Empty,
+        std::io::Sink,
+    }
+};
+
+#[cfg(feature = "std")]
+non_recursive_near_instant! {
+    {for T} std::sync::mpsc::Sender<T>,
+    {for T} std::sync::mpsc::SyncSender<T>,
+}
+
+/// Implement `MirroredClone<NearInstant>` for handles to the standard streams, which are not
+/// `Clone`, by getting a new handle to the same global stream.
+#[cfg(feature = "std")]
+macro_rules! std_stream {
+    ($($type:ty => $getter:path),* $(,)?) => {
+        $(
+            impl MirroredClone<NearInstant> for $type {
+                #[inline]
+                fn mirrored_clone(&self) -> Self {
+                    $getter()
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "std")]
+std_stream! {
+    std::io::Stdin  => std::io::stdin,
+    std::io::Stdout => std::io::stdout,
+    std::io::Stderr => std::io::stderr,
+}
+
+// Every read handle observes the state most recently published by the writer. There is only one
+// `WriteHandle`, which cannot be cloned in either sense. Cloning a `ReadHandle` registers the new
+// handle with the writer, which takes a lock; cloning a `ReadHandleFactory` does not.
+#[cfg(feature = "left-right")]
+non_recursive_near_instant! {
+    {for T} left_right::ReadHandleFactory<T>,
+}
+
+#[cfg(feature = "evmap")]
+non_recursive_near_instant! {
+    {
+        for K: Eq + core::hash::Hash, V, M, S: core::hash::BuildHasher
+    } evmap::ReadHandleFactory<K, V, M, S>,
+}
+
+#[cfg(feature = "left-right")]
+impl<T> MirroredClone<ConstantTime> for left_right::ReadHandle<T> {
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        self.clone()
+    }
+}
+
+#[cfg(feature = "evmap")]
+impl<K, V, M, S> MirroredClone<ConstantTime> for evmap::ReadHandle<K, V, M, S>
+where
+    K: Eq + core::hash::Hash,
+    S: core::hash::BuildHasher,
+{
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        self.clone()
+    }
+}
+
+// Cancelling either token cancels both. Cloning takes a lock on the token's node in the
+// cancellation tree.
+#[cfg(feature = "tokio-util")]
+impl MirroredClone<ConstantTime> for tokio_util::sync::CancellationToken {
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        self.clone()
+    }
+}
+
+// A cloned `Waker` wakes the same task. Cloning calls into the waker's vtable, which is not
+// guaranteed to be trivial.
+impl MirroredClone<ConstantTime> for core::task::Waker {
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        self.clone()
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -91,6 +249,11 @@ refcounted!(
     T alloc::sync::Weak<T>,
 );
 
+#[cfg(feature = "triomphe")]
+refcounted!(
+    T triomphe::Arc<T>,
+);
+
 macro_rules! function {
     ($($args:ident),*) => {
         impl<R, $($args),*> MirroredClone<NearInstant> for fn($($args),*) -> R {
@@ -126,37 +289,41 @@ pinned_refcounted! {
 function!();
 call_varargs_macro!(function);
 
-macro_rules! make_tuple_macro {
-    ($name:ident, $speed:ident, $dollar:tt) => {
-        macro_rules! $name {
-            ($dollar($dollar args:ident),+) => {
-                impl<$dollar($dollar args: MirroredClone<$speed>),+> MirroredClone<$speed>
-                for ($dollar($dollar args,)+)
-                {
-                    #[inline]
-                    fn mirrored_clone(&self) -> Self {
-                        #[expect(
-                            non_snake_case,
-                            reason = "using `Tn` as the variable of type `Tn`",
-                        )]
-                        let ($dollar($dollar args,)+) = self;
-                        (
-                            $dollar($dollar args.mirrored_clone(),)+
-                        )
-                    }
-                }
-            };
+// Every element is cloned at the same speed `S`. Read `SpeedMax` for how this supports elements
+// with different fastest speeds.
+macro_rules! tuple {
+    ($($args:ident),+) => {
+        impl<S, $($args: MirroredClone<S>),+> MirroredClone<S> for ($($args,)+)
+        where
+            S: AtLeastAsSlowAs<NonBlocking<ConstantTime>>,
+        {
+            #[inline]
+            fn mirrored_clone(&self) -> Self {
+                #[expect(
+                    non_snake_case,
+                    reason = "using `Tn` as the variable of type `Tn`",
+                )]
+                let ($($args,)+) = self;
+                (
+                    $($args.mirrored_clone(),)+
+                )
+            }
         }
     };
 }
 
-make_tuple_macro!(tuple_constant, ConstantTime, $);
-make_tuple_macro!(tuple_log, LogTime, $);
-make_tuple_macro!(tuple_any, AnySpeed, $);
+call_varargs_macro!(tuple);
 
-call_varargs_macro!(tuple_constant);
-call_varargs_macro!(tuple_log);
-call_varargs_macro!(tuple_any);
+impl<S, T, const N: usize> MirroredClone<S> for [T; N]
+where
+    S: AtLeastAsSlowAs<NonBlocking<ConstantTime>>,
+    T: MirroredClone<S>,
+{
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        self.each_ref().map(T::mirrored_clone)
+    }
+}
 
 impl<S: Speed, T: MirroredClone<S>> MirroredClone<S> for Option<T> {
     #[inline]
@@ -173,3 +340,177 @@ impl<S: Speed, T: MirroredClone<S>, E: MirroredClone<S>> MirroredClone<S> for Re
             .map_err(E::mirrored_clone)
     }
 }
+
+impl<S, B, C> MirroredClone<S> for core::ops::ControlFlow<B, C>
+where
+    S: Speed,
+    B: MirroredClone<S>,
+    C: MirroredClone<S>,
+{
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        match self {
+            Self::Continue(val) => Self::Continue(C::mirrored_clone(val)),
+            Self::Break(val)    => Self::Break(B::mirrored_clone(val)),
+        }
+    }
+}
+
+impl<S: Speed, T: MirroredClone<S>> MirroredClone<S> for core::task::Poll<T> {
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        match self {
+            Self::Ready(val) => Self::Ready(T::mirrored_clone(val)),
+            Self::Pending    => Self::Pending,
+        }
+    }
+}
+
+impl<S: Speed, T: MirroredClone<S>> MirroredClone<S> for core::ops::Range<T> {
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        T::mirrored_clone(&self.start)..T::mirrored_clone(&self.end)
+    }
+}
+
+impl<S: Speed, T: MirroredClone<S>> MirroredClone<S> for core::ops::RangeFrom<T> {
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        T::mirrored_clone(&self.start)..
+    }
+}
+
+impl<S: Speed, T: MirroredClone<S>> MirroredClone<S> for core::ops::RangeTo<T> {
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        ..T::mirrored_clone(&self.end)
+    }
+}
+
+impl<S: Speed, T: MirroredClone<S>> MirroredClone<S> for core::ops::RangeToInclusive<T> {
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        ..=T::mirrored_clone(&self.end)
+    }
+}
+
+/// An exhausted range remains exhausted in the clone, which is why `RangeInclusive<T>` is
+/// required to be an iterator.
+impl<S, T> MirroredClone<S> for core::ops::RangeInclusive<T>
+where
+    S: Speed,
+    T: MirroredClone<S> + PartialOrd,
+    Self: Iterator,
+{
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        let mut range = Self::new(
+            T::mirrored_clone(self.start()),
+            T::mirrored_clone(self.end()),
+        );
+        // An exhausted `RangeInclusive` has equal bounds, so advancing the new range once
+        // exhausts it.
+        if self.is_empty() && !range.is_empty() {
+            range.next();
+        }
+        range
+    }
+}
+
+impl<S: Speed, T: MirroredClone<S>> MirroredClone<S> for core::ops::Bound<T> {
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        self.as_ref().map(T::mirrored_clone)
+    }
+}
+
+// A collection of mirrored clones is a mirrored clone of the collection, element-wise; as with
+// `Option<T>`, the structure of the collection itself is not shared.
+macro_rules! map_and_collect {
+    ($($t:ident $({$($where_bounds:tt)*})? $type:ty),* $(,)?) => {
+        $(
+            #[cfg(feature = "alloc")]
+            impl<S, $t: MirroredClone<S>> MirroredClone<S> for $type
+            where
+                S: AtLeastAsSlowAs<LinearTime>,
+                $($($where_bounds)*)?
+            {
+                #[inline]
+                fn mirrored_clone(&self) -> Self {
+                    self.iter()
+                        .map($t::mirrored_clone)
+                        .collect()
+                }
+            }
+        )*
+    };
+}
+
+map_and_collect! {
+    T alloc::boxed::Box<[T]>,
+    T alloc::vec::Vec<T>,
+    T alloc::collections::VecDeque<T>,
+}
+
+#[cfg(feature = "smallvec")]
+impl<S, A> MirroredClone<S> for smallvec::SmallVec<A>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    A: smallvec::Array<Item: MirroredClone<S>>,
+{
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        self.iter()
+            .map(A::Item::mirrored_clone)
+            .collect()
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<S, T, const CAP: usize> MirroredClone<S> for arrayvec::ArrayVec<T, CAP>
+where
+    S: AtLeastAsSlowAs<ConstantTime>,
+    T: MirroredClone<S>,
+{
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        self.iter()
+            .map(T::mirrored_clone)
+            .collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S, K, V> MirroredClone<S> for alloc::collections::BTreeMap<K, V>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    K: MirroredClone<S> + Ord,
+    V: MirroredClone<S>,
+{
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        self.iter()
+            .map(|(key, val)| (K::mirrored_clone(key), V::mirrored_clone(val)))
+            .collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S, K, V, H> MirroredClone<S> for std::collections::HashMap<K, V, H>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    K: MirroredClone<S> + Eq + core::hash::Hash,
+    V: MirroredClone<S>,
+    H: IndependentClone<S> + core::hash::BuildHasher,
+{
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        // The hasher is cloned so that keys hash the same way; it has no state shared with the map.
+        let mut map = Self::with_capacity_and_hasher(
+            self.len(),
+            H::independent_clone(self.hasher()),
+        );
+        map.extend(self.iter().map(|(key, val)| (K::mirrored_clone(key), V::mirrored_clone(val))));
+        map
+    }
+}
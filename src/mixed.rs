@@ -1,7 +1,10 @@
 #![expect(clippy::absolute_paths, reason = "there's a lot of random types used")]
 #![warn(clippy::missing_inline_in_public_items, reason = "almost everything is very short")]
 
-use crate::speed::{Speed, NearInstant};
+use crate::call_varargs_macro;
+use crate::speed::{Speed, AtLeastAsSlowAs, NearInstant, ConstantTime, NonBlocking};
+#[cfg(feature = "alloc")]
+use crate::speed::LinearTime;
 
 
 /// Get clones that could share some but not all semantically-important mutable state.
@@ -14,9 +17,9 @@ use crate::speed::{Speed, NearInstant};
 /// not a catch-all for "some sort of clone, maybe also implements [`IndependentClone`] or
 /// [`MirroredClone`]".
 ///
-/// This crate *will* provide a better tool for abstracting over all three modes of cloning
-/// provided. It just isn't provided yet. This isn't it.
+/// To abstract over all three modes of cloning provided, use [`CloneBehavior`]. This isn't it.
 ///
+/// [`CloneBehavior`]: crate::CloneBehavior
 /// [`IndependentClone`]: crate::IndependentClone
 /// [`IndependentClone::independent_clone`]: crate::IndependentClone::independent_clone
 /// [`MirroredClone`]: crate::MirroredClone
@@ -60,6 +63,74 @@ impl<T> MixedClone<NearInstant> for core::sync::atomic::AtomicPtr<T> {
     }
 }
 
+/// The contents are copied into a new buffer, so this is also an independent clone.
+#[cfg(feature = "bytes")]
+impl MixedClone<LinearTime> for bytes::BytesMut {
+    #[inline]
+    fn mixed_clone(&self) -> Self {
+        self.clone()
+    }
+}
+
+/// The clone sends to the same channel, but has its own guaranteed slot in the channel's buffer,
+/// and is not parked even if its source is.
+///
+/// Allocates a new handle to track whether the clone is parked.
+#[cfg(feature = "futures")]
+impl<T> MixedClone<ConstantTime> for futures::channel::mpsc::Sender<T> {
+    #[inline]
+    fn mixed_clone(&self) -> Self {
+        self.clone()
+    }
+}
+
+#[cfg(feature = "portable-atomic")]
+impl<T> MixedClone<NearInstant> for portable_atomic::AtomicPtr<T> {
+    #[inline]
+    fn mixed_clone(&self) -> Self {
+        Self::new(self.load(portable_atomic::Ordering::Relaxed))
+    }
+}
+
+/// The clone receives the same values, but tracks separately whether it has seen the latest one.
+/// It starts out having seen the same values as its source.
+#[cfg(feature = "tokio")]
+impl<T> MixedClone<NearInstant> for tokio::sync::watch::Receiver<T> {
+    #[inline]
+    fn mixed_clone(&self) -> Self {
+        self.clone()
+    }
+}
+
+/// The clone receives values from the same channel, but has its own position in the channel.
+/// Unlike its source, it starts at the tail of the channel, and only receives values sent after
+/// the clone is made.
+///
+/// Uses [`resubscribe`], which takes the lock on the channel's tail.
+///
+/// [`resubscribe`]: tokio::sync::broadcast::Receiver::resubscribe
+#[cfg(feature = "tokio")]
+impl<T: Clone> MixedClone<ConstantTime> for tokio::sync::broadcast::Receiver<T> {
+    #[inline]
+    fn mixed_clone(&self) -> Self {
+        self.resubscribe()
+    }
+}
+
+/// Returns a child token, which is cancelled whenever its source is cancelled. Cancelling the
+/// child token does not cancel its source.
+///
+/// Uses [`child_token`], which takes a lock on the source's node in the cancellation tree.
+///
+/// [`child_token`]: tokio_util::sync::CancellationToken::child_token
+#[cfg(feature = "tokio-util")]
+impl MixedClone<ConstantTime> for tokio_util::sync::CancellationToken {
+    #[inline]
+    fn mixed_clone(&self) -> Self {
+        self.child_token()
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl<S: Speed, T: ?Sized + alloc::borrow::ToOwned> MixedClone<S> for alloc::borrow::Cow<'_, T> {
     #[inline]
@@ -68,4 +139,117 @@ impl<S: Speed, T: ?Sized + alloc::borrow::ToOwned> MixedClone<S> for alloc::borr
     }
 }
 
-// TODO: consider implementing ones with recursive constraints
+// Every element is cloned at the same speed `S`, as for `IndependentClone` and `MirroredClone`.
+// There is no generic implementation for `Box<T>`, for the coherence reasons described for
+// `IndependentClone`; `impl_for_box!` implements `MixedClone` for boxes of a crate's own types.
+macro_rules! tuple {
+    ($($args:ident),+) => {
+        impl<S, $($args: MixedClone<S>),+> MixedClone<S> for ($($args,)+)
+        where
+            S: AtLeastAsSlowAs<NonBlocking<ConstantTime>>,
+        {
+            #[inline]
+            fn mixed_clone(&self) -> Self {
+                #[expect(
+                    non_snake_case,
+                    reason = "using `Tn` as the variable of type `Tn`",
+                )]
+                let ($($args,)+) = self;
+                (
+                    $($args.mixed_clone(),)+
+                )
+            }
+        }
+    };
+}
+
+call_varargs_macro!(tuple);
+
+impl<S, T, const N: usize> MixedClone<S> for [T; N]
+where
+    S: AtLeastAsSlowAs<NonBlocking<ConstantTime>>,
+    T: MixedClone<S>,
+{
+    #[inline]
+    fn mixed_clone(&self) -> Self {
+        self.each_ref().map(T::mixed_clone)
+    }
+}
+
+impl<S: Speed, T: MixedClone<S>> MixedClone<S> for Option<T> {
+    #[inline]
+    fn mixed_clone(&self) -> Self {
+        self.as_ref().map(T::mixed_clone)
+    }
+}
+
+impl<S: Speed, T: MixedClone<S>, E: MixedClone<S>> MixedClone<S> for Result<T, E> {
+    #[inline]
+    fn mixed_clone(&self) -> Self {
+        self.as_ref()
+            .map(T::mixed_clone)
+            .map_err(E::mixed_clone)
+    }
+}
+
+impl<S: Speed, B: MixedClone<S>, C: MixedClone<S>> MixedClone<S> for core::ops::ControlFlow<B, C> {
+    #[inline]
+    fn mixed_clone(&self) -> Self {
+        match self {
+            Self::Continue(val) => Self::Continue(C::mixed_clone(val)),
+            Self::Break(val)    => Self::Break(B::mixed_clone(val)),
+        }
+    }
+}
+
+impl<S: Speed, T: MixedClone<S>> MixedClone<S> for core::task::Poll<T> {
+    #[inline]
+    fn mixed_clone(&self) -> Self {
+        match self {
+            Self::Ready(val) => Self::Ready(T::mixed_clone(val)),
+            Self::Pending    => Self::Pending,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S, T> MixedClone<S> for alloc::vec::Vec<T>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    T: MixedClone<S>,
+{
+    #[inline]
+    fn mixed_clone(&self) -> Self {
+        self.iter()
+            .map(T::mixed_clone)
+            .collect()
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<S, A> MixedClone<S> for smallvec::SmallVec<A>
+where
+    S: AtLeastAsSlowAs<LinearTime>,
+    A: smallvec::Array<Item: MixedClone<S>>,
+{
+    #[inline]
+    fn mixed_clone(&self) -> Self {
+        self.iter()
+            .map(A::Item::mixed_clone)
+            .collect()
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<S, T, const CAP: usize> MixedClone<S> for arrayvec::ArrayVec<T, CAP>
+where
+    S: AtLeastAsSlowAs<ConstantTime>,
+    T: MixedClone<S>,
+{
+    #[inline]
+    fn mixed_clone(&self) -> Self {
+        self.iter()
+            .map(T::mixed_clone)
+            .collect()
+    }
+}
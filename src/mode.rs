@@ -0,0 +1,39 @@
+mod sealed {
+    #[expect(unnameable_types, reason = "This is intentional, and creates a sealed trait")]
+    pub trait Sealed {}
+}
+
+
+use self::sealed::Sealed;
+
+
+/// Trait for indicating the semantic behavior of a cloning operation.
+///
+/// Used with [`CloneBehavior`] to write generic code once over each mode of cloning.
+///
+/// [`CloneBehavior`]: crate::CloneBehavior
+pub trait CloneMode: Sealed {}
+
+/// Indicates clones which do not share any semantically-important mutable state, as provided by
+/// [`IndependentClone`].
+///
+/// [`IndependentClone`]: crate::IndependentClone
+pub enum IndependentMode {}
+/// Indicates clones which share all semantically-important mutable state, as provided by
+/// [`MirroredClone`].
+///
+/// [`MirroredClone`]: crate::MirroredClone
+pub enum MirroredMode {}
+/// Indicates clones which could share some but not all semantically-important mutable state, as
+/// provided by [`MixedClone`].
+///
+/// [`MixedClone`]: crate::MixedClone
+pub enum MixedMode {}
+
+impl CloneMode for IndependentMode {}
+impl CloneMode for MirroredMode {}
+impl CloneMode for MixedMode {}
+
+impl Sealed for IndependentMode {}
+impl Sealed for MirroredMode {}
+impl Sealed for MixedMode {}
@@ -0,0 +1,200 @@
+//! Functions used by the `independent_clone_roundtrip!` and `mirrored_clone_consistency!` macros.
+
+#![expect(
+    clippy::impl_trait_in_params,
+    reason = "allows the speed to be given with a turbofish, without listing every generic",
+)]
+
+use core::fmt::Debug;
+
+use proptest::{prop_assert_eq, test_runner::TestCaseError};
+
+use crate::{independent::IndependentClone, mirrored::MirroredClone, speed::Speed};
+
+
+/// Check that `value` and its independent clone at speed `S` are initially observed to be equal,
+/// and that applying `mutations` to either one does not affect how the other is observed.
+///
+/// # Errors
+/// Returns an error describing the first violated condition.
+pub fn check_independent<S, T, M, O>(
+    value:     T,
+    mutations: &[M],
+    mutate:    impl Fn(&mut T, &M),
+    observe:   impl Fn(&T) -> O,
+) -> Result<(), TestCaseError>
+where
+    S: Speed,
+    T: IndependentClone<S>,
+    O: PartialEq + Debug,
+{
+    let mut source = value;
+    let mut clone = source.independent_clone();
+    let source_before = observe(&source);
+    prop_assert_eq!(
+        &observe(&clone), &source_before,
+        "an independent clone should initially be observed to be equal to its source",
+    );
+
+    for mutation in mutations {
+        mutate(&mut clone, mutation);
+    }
+    let clone_after = observe(&clone);
+    prop_assert_eq!(
+        &observe(&source), &source_before,
+        "mutating an independent clone should not affect its source",
+    );
+
+    for mutation in mutations {
+        mutate(&mut source, mutation);
+    }
+    prop_assert_eq!(
+        &observe(&clone), &clone_after,
+        "mutating the source of an independent clone should not affect the clone",
+    );
+
+    Ok(())
+}
+
+/// Check that `value` and its mirrored clone at speed `S` are observed to be equal initially and
+/// after each of `mutations`, which are applied alternately to the clone and to `value`.
+///
+/// # Errors
+/// Returns an error describing the first violated condition.
+pub fn check_mirrored<S, T, M, O>(
+    value:     T,
+    mutations: &[M],
+    mutate:    impl Fn(&mut T, &M),
+    observe:   impl Fn(&T) -> O,
+) -> Result<(), TestCaseError>
+where
+    S: Speed,
+    T: MirroredClone<S>,
+    O: PartialEq + Debug,
+{
+    let mut source = value;
+    let mut clone = source.mirrored_clone();
+    prop_assert_eq!(
+        observe(&clone), observe(&source),
+        "a mirrored clone should initially be observed to be equal to its source",
+    );
+
+    for (mutation, mutate_clone) in mutations.iter().zip([true, false].into_iter().cycle()) {
+        if mutate_clone {
+            mutate(&mut clone, mutation);
+        } else {
+            mutate(&mut source, mutation);
+        }
+        prop_assert_eq!(
+            observe(&clone), observe(&source),
+            "mutating a mirrored clone or its source should be reflected by the other",
+        );
+    }
+
+    Ok(())
+}
+
+/// Generate a `proptest` test checking that independent clones of randomly generated values act
+/// independently under randomly generated sequences of mutations.
+///
+/// The test is named `$name`, and clones at speed `$speed`. Values are generated by the `values`
+/// strategy, and a sequence of up to 16 mutations is generated by the `mutations` strategy. Each
+/// mutation is applied with `mutate`, a `Fn(&mut T, &M)`, and values are compared by comparing
+/// the results of `observe`, a `Fn(&T) -> O`.
+///
+/// The independent clone must initially be observed to be equal to its source, and applying the
+/// mutations to either one must not affect how the other is observed.
+///
+/// ```
+/// use clone_behavior::{independent_clone_roundtrip, LinearTime};
+/// use proptest::prelude::any;
+///
+/// independent_clone_roundtrip! {
+///     vec_clones_are_independent: LinearTime,
+///     values:    any::<Vec<u8>>(),
+///     mutations: any::<u8>(),
+///     mutate:    |vec: &mut Vec<u8>, byte: &u8| vec.push(*byte),
+///     observe:   |vec: &Vec<u8>| vec.clone(),
+/// }
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! independent_clone_roundtrip {
+    (
+        $(#[$meta:meta])*
+        $name:ident: $speed:ty,
+        values:    $values:expr,
+        mutations: $mutations:expr,
+        mutate:    $mutate:expr,
+        observe:   $observe:expr $(,)?
+    ) => {
+        $crate::__private::proptest::proptest! {
+            $(#[$meta])*
+            #[test]
+            fn $name(
+                value in $values,
+                mutations in $crate::__private::proptest::collection::vec($mutations, 0..=16),
+            ) {
+                $crate::__private::check_independent::<$speed, _, _, _>(
+                    value,
+                    &mutations,
+                    $mutate,
+                    $observe,
+                )?;
+            }
+        }
+    };
+}
+
+/// Generate a `proptest` test checking that mirrored clones of randomly generated values stay
+/// consistent under randomly generated sequences of mutations.
+///
+/// The test is named `$name`, and clones at speed `$speed`. Values are generated by the `values`
+/// strategy, and a sequence of up to 16 mutations is generated by the `mutations` strategy. Each
+/// mutation is applied with `mutate`, a `Fn(&mut T, &M)`, and values are compared by comparing
+/// the results of `observe`, a `Fn(&T) -> O`.
+///
+/// The mutations are applied alternately to the mirrored clone and to its source, and the two
+/// must be observed to be equal initially and after every mutation.
+///
+/// ```
+/// use std::{cell::RefCell, rc::Rc};
+/// use clone_behavior::{mirrored_clone_consistency, ConstantTime};
+/// use proptest::prelude::{any, Strategy};
+///
+/// mirrored_clone_consistency! {
+///     rc_clones_are_mirrored: ConstantTime,
+///     values:    any::<u8>().prop_map(|byte| Rc::new(RefCell::new(vec![byte]))),
+///     mutations: any::<u8>(),
+///     mutate:    |vec: &mut Rc<RefCell<Vec<u8>>>, byte: &u8| vec.borrow_mut().push(*byte),
+///     observe:   |vec: &Rc<RefCell<Vec<u8>>>| vec.borrow().clone(),
+/// }
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! mirrored_clone_consistency {
+    (
+        $(#[$meta:meta])*
+        $name:ident: $speed:ty,
+        values:    $values:expr,
+        mutations: $mutations:expr,
+        mutate:    $mutate:expr,
+        observe:   $observe:expr $(,)?
+    ) => {
+        $crate::__private::proptest::proptest! {
+            $(#[$meta])*
+            #[test]
+            fn $name(
+                value in $values,
+                mutations in $crate::__private::proptest::collection::vec($mutations, 0..=16),
+            ) {
+                $crate::__private::check_mirrored::<$speed, _, _, _>(
+                    value,
+                    &mutations,
+                    $mutate,
+                    $observe,
+                )?;
+            }
+        }
+    };
+}
@@ -0,0 +1,63 @@
+use crate::{independent::IndependentClone, mirrored::MirroredClone, mixed::MixedClone};
+use crate::speed::Speed;
+
+
+/// Expose which portion of a [`MixedClone`] type's state is shared by its mixed clones, and which
+/// portion is deep-copied.
+///
+/// Ordinarily, the behavior of a mixed clone can only be described in prose documentation. This
+/// trait instead splits a value into a view of its [`Shared`] state, whose mixed clones are
+/// [mirrored clones], and a view of its [`Independent`] state, whose mixed clones are
+/// [independent clones]; the bounds on those associated types are checked by the compiler.
+///
+/// # Contract
+/// For a value `x` and its mixed clone `y = x.mixed_clone()`, at speed `S`:
+/// - `y.split_view().0` should behave as a mirrored clone of `x.split_view().0`,
+/// - `y.split_view().1` should behave as an independent clone of `x.split_view().1`, and
+/// - no other semantically-important mutable state should be shared between `x` and `y`.
+///
+/// # Example
+/// ```
+/// use std::{cell::RefCell, rc::Rc};
+/// use clone_behavior::{IndependentClone, LinearTime, MirroredClone, MixedClone, SharedParts};
+///
+/// struct Session {
+///     cache:  Rc<RefCell<Vec<u8>>>,
+///     config: Vec<u8>,
+/// }
+///
+/// impl MixedClone<LinearTime> for Session {
+///     fn mixed_clone(&self) -> Self {
+///         Self {
+///             cache:  MirroredClone::<LinearTime>::mirrored_clone(&self.cache),
+///             config: IndependentClone::<LinearTime>::independent_clone(&self.config),
+///         }
+///     }
+/// }
+///
+/// impl SharedParts<LinearTime> for Session {
+///     type Shared = Rc<RefCell<Vec<u8>>>;
+///     type Independent = Vec<u8>;
+///
+///     fn split_view(&self) -> (&Self::Shared, &Self::Independent) {
+///         (&self.cache, &self.config)
+///     }
+/// }
+/// ```
+///
+/// [`Shared`]: SharedParts::Shared
+/// [`Independent`]: SharedParts::Independent
+/// [mirrored clones]: MirroredClone
+/// [independent clones]: IndependentClone
+pub trait SharedParts<S: Speed>: MixedClone<S> {
+    /// The portion of the state which is shared between mixed clones.
+    type Shared: MirroredClone<S>;
+    /// The portion of the state which is deep-copied by mixed clones.
+    type Independent: IndependentClone<S>;
+
+    /// Split a view of the value into its shared and independent portions.
+    ///
+    /// Read [`SharedParts`] for more.
+    #[must_use]
+    fn split_view(&self) -> (&Self::Shared, &Self::Independent);
+}
@@ -0,0 +1,104 @@
+#![cfg_attr(
+    feature = "alloc",
+    expect(clippy::absolute_paths, reason = "there's a lot of random types used"),
+)]
+#![warn(clippy::missing_inline_in_public_items, reason = "almost everything is very short")]
+
+use crate::independent::IndependentClone;
+use crate::speed::Speed;
+#[cfg(feature = "std")]
+use crate::speed::{AtLeastAsSlowAs, ConstantTime};
+
+
+/// Take an independent, point-in-time copy of the value behind a mirrored handle.
+///
+/// Types like `Arc<T>` or [`Shared<T>`] are handles whose [mirrored clones] all refer to the same
+/// value. A snapshot is an [independent clone] of that value, of type
+/// [`Snapshot`](Self::Snapshot), taken at a single point in time; for handles guarding their value
+/// with a lock, the lock is held for the duration of the clone, so the snapshot is consistent.
+///
+/// The snapshot does not share any semantically-important mutable state with the handle it was
+/// taken from, or with any of that handle's mirrored clones.
+///
+/// [`Shared<T>`]: crate::Shared
+/// [mirrored clones]: crate::MirroredClone
+/// [independent clone]: IndependentClone
+pub trait Snapshot<S: Speed> {
+    /// The type of an independent copy of the value behind the handle.
+    type Snapshot: IndependentClone<S>;
+
+    /// Take an independent, point-in-time copy of the value behind the handle, at speed `S`.
+    ///
+    /// Read [`Snapshot`] for more.
+    #[must_use]
+    fn snapshot(&self) -> Self::Snapshot;
+}
+
+
+#[cfg(feature = "alloc")]
+impl<S: Speed, T: IndependentClone<S>> Snapshot<S> for alloc::rc::Rc<T> {
+    type Snapshot = T;
+
+    #[inline]
+    fn snapshot(&self) -> Self::Snapshot {
+        T::independent_clone(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: Speed, T: IndependentClone<S>> Snapshot<S> for alloc::sync::Arc<T> {
+    type Snapshot = T;
+
+    #[inline]
+    fn snapshot(&self) -> Self::Snapshot {
+        T::independent_clone(self)
+    }
+}
+
+#[cfg(feature = "triomphe")]
+impl<S: Speed, T: IndependentClone<S>> Snapshot<S> for triomphe::Arc<T> {
+    type Snapshot = T;
+
+    #[inline]
+    fn snapshot(&self) -> Self::Snapshot {
+        T::independent_clone(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S, T> Snapshot<S> for std::sync::RwLock<T>
+where
+    S: AtLeastAsSlowAs<ConstantTime>,
+    T: IndependentClone<S>,
+{
+    type Snapshot = T;
+
+    /// # Panics
+    /// Panics if the `RwLock` is poisoned.
+    #[inline]
+    fn snapshot(&self) -> Self::Snapshot {
+        let lock_result: Result<_, std::sync::PoisonError<_>> = self.read();
+        #[expect(clippy::unwrap_used, reason = "Unwrapping poison")]
+        T::independent_clone(&lock_result.unwrap())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S, T> Snapshot<S> for std::sync::Mutex<T>
+where
+    S: AtLeastAsSlowAs<ConstantTime>,
+    T: IndependentClone<S>,
+{
+    type Snapshot = T;
+
+    /// # Panics or Deadlocks
+    /// Panics if the `Mutex` is poisoned.
+    ///
+    /// Will either panic or deadlock if the current thread already holds the mutex.
+    #[inline]
+    fn snapshot(&self) -> Self::Snapshot {
+        let lock_result: Result<_, std::sync::PoisonError<_>> = self.lock();
+        #[expect(clippy::unwrap_used, reason = "Unwrapping poison")]
+        T::independent_clone(&lock_result.unwrap())
+    }
+}
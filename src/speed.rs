@@ -4,11 +4,82 @@ mod sealed {
 }
 
 
+use core::{convert::Infallible, marker::PhantomData};
+
 use self::sealed::Sealed;
 
 
 /// Trait for indicating the overhead and/or time complexity of a cloning operation.
-pub trait Speed: Sealed {}
+///
+/// A cloning operation which satisfies the constraints of one speed also satisfies the constraints
+/// of every slower speed. Accordingly, a type implementing a cloning trait at some speed should
+/// also implement it at every speed which is [`AtLeastAsSlowAs`] that speed, so that a bound like
+/// `T: IndependentClone<AnySpeed>` is satisfied by every type with an independent clone.
+///
+/// Types whose clones do not depend on any generics can get those implementations from
+/// [`NonRecursive`]; other types can use [`AtLeastAsSlowAs`] to cover every applicable speed with a
+/// single implementation.
+///
+/// [`NonRecursive`]: crate::NonRecursive
+pub trait Speed: Sealed {
+    /// The time complexity and overhead indicated by this speed, ignoring whether it is
+    /// [amortized](Self::AMORTIZED) or [nonblocking](Self::NON_BLOCKING).
+    const CLASS: SpeedClass;
+    /// Whether this speed is [`Amortized`].
+    const AMORTIZED: bool = false;
+    /// Whether this speed guarantees that cloning operations are nonblocking, as [`NearInstant`]
+    /// and [`NonBlocking`] do.
+    const NON_BLOCKING: bool = false;
+}
+
+/// The time complexity and overhead indicated by a [`Speed`], for use at runtime.
+///
+/// Each variant corresponds to the [`Speed`] of the same name. Variants are ordered from fastest to
+/// slowest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SpeedClass {
+    /// Corresponds to [`NearInstant`].
+    NearInstant,
+    /// Corresponds to [`ConstantTime`].
+    ConstantTime,
+    /// Corresponds to [`LogTime`].
+    LogTime,
+    /// Corresponds to [`LinearTime`].
+    LinearTime,
+    /// Corresponds to [`AnySpeed`].
+    AnySpeed,
+}
+
+/// Indicates that a [`Speed`] places no more constraints on a cloning operation than `S` does.
+///
+/// Every speed is at least as slow as itself, and [`AnySpeed`] is at least as slow as every speed.
+///
+/// This is useful for implementing a cloning trait at every speed which is slow enough for a
+/// generic type, with a single implementation. For instance, the following implements
+/// `IndependentClone<S>` for a list at `NonBlocking<ConstantTime>` and every slower speed, whenever
+/// the elements implement `IndependentClone<S>`; as such, if the elements implement
+/// `IndependentClone<NearInstant>` (and the slower speeds), so does the list at every speed it
+/// supports.
+///
+/// ```
+/// use clone_behavior::{AtLeastAsSlowAs, ConstantTime, IndependentClone, NonBlocking};
+///
+/// struct ShortList<T>([Option<T>; 4]);
+///
+/// impl<S, T> IndependentClone<S> for ShortList<T>
+/// where
+///     S: AtLeastAsSlowAs<NonBlocking<ConstantTime>>,
+///     T: IndependentClone<S>,
+/// {
+///     fn independent_clone(&self) -> Self {
+///         Self(self.0.independent_clone())
+///     }
+/// }
+/// ```
+///
+/// Note that the compiler cannot use the transitivity of this relation; a bound of
+/// `S: AtLeastAsSlowAs<ConstantTime>` does not imply `S: AtLeastAsSlowAs<NearInstant>`.
+pub trait AtLeastAsSlowAs<S: Speed>: Speed {}
 
 /// Indicates that a cloning operation is:
 /// - constant time
@@ -24,15 +95,345 @@ pub enum NearInstant {}
 pub enum ConstantTime {}
 /// Indicates that a cloning operation operates in logarithmic time or faster.
 pub enum LogTime {}
+/// Indicates that a cloning operation operates in time linear in the size of the value (including
+/// any data it owns), or faster.
+pub enum LinearTime {}
 /// Places no constraint on the overhead or time complexity of a cloning operation.
 pub enum AnySpeed {}
 
-impl Speed for NearInstant {}
-impl Speed for ConstantTime {}
-impl Speed for LogTime {}
-impl Speed for AnySpeed {}
+/// Indicates that any sequence of cloning operations takes at most the time indicated by `S` per
+/// clone on average, though an individual cloning operation may be slower.
+///
+/// This is suitable for types like copy-on-write or lazily-copied buffers, whose clones are usually
+/// cheap but occasionally expensive. An amortized speed is at least as slow as the speed it
+/// amortizes, but is not at least as slow as any faster non-amortized speed; for instance,
+/// `Amortized<ConstantTime>` is not [`AtLeastAsSlowAs`] [`LogTime`].
+///
+/// `Amortized<S>` is a [`Speed`] when `S` is [`ConstantTime`], [`LogTime`], or [`LinearTime`].
+pub struct Amortized<S>(PhantomData<S>, Infallible);
+
+/// Indicates that a cloning operation satisfies the constraints of `S`, and is also nonblocking.
+///
+/// A nonblocking cloning operation does not acquire locks, allocate or deallocate memory, or
+/// perform any other operation which could block the current thread. Atomic operations are fine.
+///
+/// This is orthogonal to the time complexity of a cloning operation, and is suitable for bounding
+/// clones performed in contexts like signal handlers or realtime audio callbacks. For instance,
+/// [`ConstantTime`] permits acquiring a lock, while `NonBlocking<ConstantTime>` does not.
+/// [`NearInstant`] is already nonblocking, and is at least as fast as `NonBlocking<ConstantTime>`.
+///
+/// `NonBlocking<S>` is a [`Speed`] when `S` is [`ConstantTime`], [`LogTime`], [`LinearTime`], or
+/// [`AnySpeed`].
+pub struct NonBlocking<S>(PhantomData<S>, Infallible);
+
+/// Computes the maximum of two speeds: the fastest speed which is [`AtLeastAsSlowAs`] both `Self`
+/// and `Rhs`.
+///
+/// This is implemented for every pair of speeds provided by this crate. For a [`CustomSpeed`],
+/// it is implemented with other custom speeds of the same tag, with [`AnySpeed`], and with each
+/// speed which its baseline is at least as slow as.
+///
+/// Since a type which can be cloned at some speed should also be cloned at every slower speed, a
+/// value made of parts which can be cloned at speeds `S1` and `S2` can be cloned at `Max<S1, S2>`.
+/// This is how the tuple implementations of the cloning traits support elements with different
+/// fastest speeds: they clone every element at the same speed `S`, so a tuple `(A, B)` with
+/// `A: IndependentClone<S1>` and `B: IndependentClone<S2>` implements
+/// `IndependentClone<Max<S1, S2>>` as long as `A` and `B` also implement the slower speeds, as
+/// [`NonRecursive`] types do. A tuple implementation with a separate speed parameter for each
+/// element is not possible, since those parameters would not be constrained by the implementation.
+///
+/// The [`Max`] alias is usually more convenient.
+///
+/// [`NonRecursive`]: crate::NonRecursive
+pub trait SpeedMax<Rhs: Speed>: Speed + Sized {
+    /// The fastest speed which is at least as slow as both `Self` and `Rhs`.
+    type Output: AtLeastAsSlowAs<Self> + AtLeastAsSlowAs<Rhs>;
+}
+
+/// The fastest speed which is [`AtLeastAsSlowAs`] both `S1` and `S2`.
+///
+/// For example, `Max<ConstantTime, LogTime>` is `LogTime`, and
+/// `Max<Amortized<ConstantTime>, NonBlocking<LogTime>>` is `Amortized<LogTime>`.
+pub type Max<S1, S2> = <S1 as SpeedMax<S2>>::Output;
+
+/// A user-defined [`Speed`], identified by the [`SpeedTag`] `Tag`.
+///
+/// This allows downstream crates to model their own cost classes, such as clones which might
+/// perform network or disk I/O. A custom speed is [`AtLeastAsSlowAs`] its tag's
+/// [`Baseline`](SpeedTag::Baseline) speed (and every speed which the baseline is at least as slow
+/// as), and [`AnySpeed`] is at least as slow as every custom speed. Types which are
+/// [`NonRecursive`] can be cloned at a custom speed whenever they can be cloned at its baseline.
+///
+/// For runtime introspection, a custom speed has the same [`CLASS`](Speed::CLASS),
+/// [`AMORTIZED`](Speed::AMORTIZED), and [`NON_BLOCKING`](Speed::NON_BLOCKING) values as its
+/// baseline.
+///
+/// ```
+/// use clone_behavior::{AnySpeed, CustomSpeed, IndependentClone, SpeedTag};
+///
+/// /// Indicates a clone which might need to perform network requests.
+/// enum NetworkBoundTag {}
+///
+/// impl SpeedTag for NetworkBoundTag {
+///     type Baseline = AnySpeed;
+/// }
+///
+/// type NetworkBound = CustomSpeed<NetworkBoundTag>;
+///
+/// fn clone_remote<T: IndependentClone<NetworkBound>>(value: &T) -> T {
+///     value.independent_clone()
+/// }
+///
+/// assert_eq!(clone_remote(&5_u32), 5);
+/// ```
+///
+/// [`NonRecursive`]: crate::NonRecursive
+pub struct CustomSpeed<Tag>(PhantomData<Tag>, Infallible);
+
+/// Identifies a user-defined [`CustomSpeed`].
+pub trait SpeedTag {
+    /// The fastest speed which `CustomSpeed<Self>` is at least as slow as.
+    type Baseline: Speed;
+}
+
+impl Speed for NearInstant {
+    const CLASS: SpeedClass = SpeedClass::NearInstant;
+    const NON_BLOCKING: bool = true;
+}
+impl Speed for ConstantTime {
+    const CLASS: SpeedClass = SpeedClass::ConstantTime;
+}
+impl Speed for LogTime {
+    const CLASS: SpeedClass = SpeedClass::LogTime;
+}
+impl Speed for LinearTime {
+    const CLASS: SpeedClass = SpeedClass::LinearTime;
+}
+impl Speed for AnySpeed {
+    const CLASS: SpeedClass = SpeedClass::AnySpeed;
+}
+
+macro_rules! amortized {
+    ($($speed:ident),*) => {
+        $(
+            impl Speed for Amortized<$speed> {
+                const CLASS: SpeedClass = SpeedClass::$speed;
+                const AMORTIZED: bool = true;
+            }
+        )*
+    };
+}
+
+amortized!(ConstantTime, LogTime, LinearTime);
+
+macro_rules! non_blocking {
+    ($($speed:ident),*) => {
+        $(
+            impl Speed for NonBlocking<$speed> {
+                const CLASS: SpeedClass = SpeedClass::$speed;
+                const NON_BLOCKING: bool = true;
+            }
+        )*
+    };
+}
+
+non_blocking!(ConstantTime, LogTime, LinearTime, AnySpeed);
+
+impl<Tag: SpeedTag> Speed for CustomSpeed<Tag> {
+    const CLASS: SpeedClass = Tag::Baseline::CLASS;
+    const AMORTIZED: bool = Tag::Baseline::AMORTIZED;
+    const NON_BLOCKING: bool = Tag::Baseline::NON_BLOCKING;
+}
+
+macro_rules! at_least_as_slow_as {
+    ($($slower:ty: $($faster:ty),+;)*) => {
+        $($(
+            impl AtLeastAsSlowAs<$faster> for $slower {}
+        )+)*
+    };
+}
+
+at_least_as_slow_as! {
+    NearInstant:  NearInstant;
+    ConstantTime: NearInstant, ConstantTime;
+    LogTime:      NearInstant, ConstantTime, LogTime;
+    LinearTime:   NearInstant, ConstantTime, LogTime, LinearTime;
+    AnySpeed:     NearInstant, ConstantTime, LogTime, LinearTime, AnySpeed;
+
+    Amortized<ConstantTime>: NearInstant, ConstantTime, Amortized<ConstantTime>;
+    Amortized<LogTime>:      NearInstant, ConstantTime, LogTime,
+                             Amortized<ConstantTime>, Amortized<LogTime>;
+    Amortized<LinearTime>:   NearInstant, ConstantTime, LogTime, LinearTime,
+                             Amortized<ConstantTime>, Amortized<LogTime>, Amortized<LinearTime>;
+    AnySpeed: Amortized<ConstantTime>, Amortized<LogTime>, Amortized<LinearTime>;
+
+    NonBlocking<ConstantTime>: NearInstant, NonBlocking<ConstantTime>;
+    NonBlocking<LogTime>:      NearInstant, NonBlocking<ConstantTime>, NonBlocking<LogTime>;
+    NonBlocking<LinearTime>:   NearInstant, NonBlocking<ConstantTime>, NonBlocking<LogTime>,
+                               NonBlocking<LinearTime>;
+    NonBlocking<AnySpeed>:     NearInstant, NonBlocking<ConstantTime>, NonBlocking<LogTime>,
+                               NonBlocking<LinearTime>, NonBlocking<AnySpeed>;
+
+    ConstantTime: NonBlocking<ConstantTime>;
+    LogTime:      NonBlocking<ConstantTime>, NonBlocking<LogTime>;
+    LinearTime:   NonBlocking<ConstantTime>, NonBlocking<LogTime>, NonBlocking<LinearTime>;
+    AnySpeed:     NonBlocking<ConstantTime>, NonBlocking<LogTime>, NonBlocking<LinearTime>,
+                  NonBlocking<AnySpeed>;
+
+    Amortized<ConstantTime>: NonBlocking<ConstantTime>;
+    Amortized<LogTime>:      NonBlocking<ConstantTime>, NonBlocking<LogTime>;
+    Amortized<LinearTime>:   NonBlocking<ConstantTime>, NonBlocking<LogTime>,
+                             NonBlocking<LinearTime>;
+}
+
+macro_rules! custom_at_least_as_slow_as {
+    ($($faster:ty),* $(,)?) => {
+        $(
+            impl<Tag> AtLeastAsSlowAs<$faster> for CustomSpeed<Tag>
+            where
+                Tag:           SpeedTag,
+                Tag::Baseline: AtLeastAsSlowAs<$faster>,
+            {}
+        )*
+    };
+}
+
+custom_at_least_as_slow_as! {
+    NearInstant, ConstantTime, LogTime, LinearTime, AnySpeed,
+    Amortized<ConstantTime>, Amortized<LogTime>, Amortized<LinearTime>,
+    NonBlocking<ConstantTime>, NonBlocking<LogTime>, NonBlocking<LinearTime>, NonBlocking<AnySpeed>,
+}
+
+impl<Tag: SpeedTag> AtLeastAsSlowAs<Self> for CustomSpeed<Tag> {}
+impl<Tag: SpeedTag> AtLeastAsSlowAs<CustomSpeed<Tag>> for AnySpeed {}
+
+macro_rules! speed_max {
+    (@reflexive $($speed:ty),* $(,)?) => {
+        $(
+            impl SpeedMax<$speed> for $speed {
+                type Output = $speed;
+            }
+        )*
+    };
+    ($($lhs:ty: $($rhs:ty => $output:ty),+;)*) => {
+        $($(
+            impl SpeedMax<$rhs> for $lhs {
+                type Output = $output;
+            }
+            impl SpeedMax<$lhs> for $rhs {
+                type Output = $output;
+            }
+        )+)*
+    };
+}
+
+speed_max! {
+    @reflexive
+    NearInstant, ConstantTime, LogTime, LinearTime, AnySpeed,
+    Amortized<ConstantTime>, Amortized<LogTime>, Amortized<LinearTime>,
+    NonBlocking<ConstantTime>, NonBlocking<LogTime>, NonBlocking<LinearTime>, NonBlocking<AnySpeed>,
+}
+
+speed_max! {
+    NearInstant: ConstantTime => ConstantTime, LogTime => LogTime, LinearTime => LinearTime,
+                 AnySpeed => AnySpeed, Amortized<ConstantTime> => Amortized<ConstantTime>,
+                 Amortized<LogTime> => Amortized<LogTime>,
+                 Amortized<LinearTime> => Amortized<LinearTime>,
+                 NonBlocking<ConstantTime> => NonBlocking<ConstantTime>,
+                 NonBlocking<LogTime> => NonBlocking<LogTime>,
+                 NonBlocking<LinearTime> => NonBlocking<LinearTime>,
+                 NonBlocking<AnySpeed> => NonBlocking<AnySpeed>;
+    ConstantTime: LogTime => LogTime, LinearTime => LinearTime, AnySpeed => AnySpeed,
+                  Amortized<ConstantTime> => Amortized<ConstantTime>,
+                  Amortized<LogTime> => Amortized<LogTime>,
+                  Amortized<LinearTime> => Amortized<LinearTime>,
+                  NonBlocking<ConstantTime> => ConstantTime, NonBlocking<LogTime> => LogTime,
+                  NonBlocking<LinearTime> => LinearTime, NonBlocking<AnySpeed> => AnySpeed;
+    LogTime: LinearTime => LinearTime, AnySpeed => AnySpeed,
+             Amortized<ConstantTime> => Amortized<LogTime>,
+             Amortized<LogTime> => Amortized<LogTime>,
+             Amortized<LinearTime> => Amortized<LinearTime>, NonBlocking<ConstantTime> => LogTime,
+             NonBlocking<LogTime> => LogTime, NonBlocking<LinearTime> => LinearTime,
+             NonBlocking<AnySpeed> => AnySpeed;
+    LinearTime: AnySpeed => AnySpeed, Amortized<ConstantTime> => Amortized<LinearTime>,
+                Amortized<LogTime> => Amortized<LinearTime>,
+                Amortized<LinearTime> => Amortized<LinearTime>,
+                NonBlocking<ConstantTime> => LinearTime, NonBlocking<LogTime> => LinearTime,
+                NonBlocking<LinearTime> => LinearTime, NonBlocking<AnySpeed> => AnySpeed;
+    AnySpeed: Amortized<ConstantTime> => AnySpeed, Amortized<LogTime> => AnySpeed,
+              Amortized<LinearTime> => AnySpeed, NonBlocking<ConstantTime> => AnySpeed,
+              NonBlocking<LogTime> => AnySpeed, NonBlocking<LinearTime> => AnySpeed,
+              NonBlocking<AnySpeed> => AnySpeed;
+    Amortized<ConstantTime>: Amortized<LogTime> => Amortized<LogTime>,
+                             Amortized<LinearTime> => Amortized<LinearTime>,
+                             NonBlocking<ConstantTime> => Amortized<ConstantTime>,
+                             NonBlocking<LogTime> => Amortized<LogTime>,
+                             NonBlocking<LinearTime> => Amortized<LinearTime>,
+                             NonBlocking<AnySpeed> => AnySpeed;
+    Amortized<LogTime>: Amortized<LinearTime> => Amortized<LinearTime>,
+                        NonBlocking<ConstantTime> => Amortized<LogTime>,
+                        NonBlocking<LogTime> => Amortized<LogTime>,
+                        NonBlocking<LinearTime> => Amortized<LinearTime>,
+                        NonBlocking<AnySpeed> => AnySpeed;
+    Amortized<LinearTime>: NonBlocking<ConstantTime> => Amortized<LinearTime>,
+                           NonBlocking<LogTime> => Amortized<LinearTime>,
+                           NonBlocking<LinearTime> => Amortized<LinearTime>,
+                           NonBlocking<AnySpeed> => AnySpeed;
+    NonBlocking<ConstantTime>: NonBlocking<LogTime> => NonBlocking<LogTime>,
+                               NonBlocking<LinearTime> => NonBlocking<LinearTime>,
+                               NonBlocking<AnySpeed> => NonBlocking<AnySpeed>;
+    NonBlocking<LogTime>: NonBlocking<LinearTime> => NonBlocking<LinearTime>,
+                          NonBlocking<AnySpeed> => NonBlocking<AnySpeed>;
+    NonBlocking<LinearTime>: NonBlocking<AnySpeed> => NonBlocking<AnySpeed>;
+}
+
+macro_rules! custom_speed_max {
+    ($($faster:ty),* $(,)?) => {
+        $(
+            impl<Tag> SpeedMax<$faster> for CustomSpeed<Tag>
+            where
+                Tag:           SpeedTag,
+                Tag::Baseline: AtLeastAsSlowAs<$faster>,
+            {
+                type Output = Self;
+            }
+            impl<Tag> SpeedMax<CustomSpeed<Tag>> for $faster
+            where
+                Tag:           SpeedTag,
+                Tag::Baseline: AtLeastAsSlowAs<$faster>,
+            {
+                type Output = CustomSpeed<Tag>;
+            }
+        )*
+    };
+}
+
+custom_speed_max! {
+    NearInstant, ConstantTime, LogTime, LinearTime,
+    Amortized<ConstantTime>, Amortized<LogTime>, Amortized<LinearTime>,
+    NonBlocking<ConstantTime>, NonBlocking<LogTime>, NonBlocking<LinearTime>, NonBlocking<AnySpeed>,
+}
+
+impl<Tag: SpeedTag> SpeedMax<Self> for CustomSpeed<Tag> {
+    type Output = Self;
+}
+impl<Tag: SpeedTag> SpeedMax<AnySpeed> for CustomSpeed<Tag> {
+    type Output = AnySpeed;
+}
+impl<Tag: SpeedTag> SpeedMax<CustomSpeed<Tag>> for AnySpeed {
+    type Output = Self;
+}
 
 impl Sealed for NearInstant {}
 impl Sealed for ConstantTime {}
 impl Sealed for LogTime {}
+impl Sealed for LinearTime {}
 impl Sealed for AnySpeed {}
+impl Sealed for Amortized<ConstantTime> {}
+impl Sealed for Amortized<LogTime> {}
+impl Sealed for Amortized<LinearTime> {}
+impl Sealed for NonBlocking<ConstantTime> {}
+impl Sealed for NonBlocking<LogTime> {}
+impl Sealed for NonBlocking<LinearTime> {}
+impl Sealed for NonBlocking<AnySpeed> {}
+impl<Tag: SpeedTag> Sealed for CustomSpeed<Tag> {}
@@ -0,0 +1,111 @@
+#![expect(clippy::absolute_paths, reason = "there's a lot of random types used")]
+
+use crate::{blanket_impls::NonRecursive, independent::IndependentClone, mirrored::MirroredClone};
+use crate::speed::NearInstant;
+
+
+/// Indicates that a type has no state at all, like a zero-sized marker or configuration type.
+///
+/// Since there is no state to share or to copy, a clone of a stateless type is simultaneously an
+/// [independent clone] and a [mirrored clone] of its source, and is performed in
+/// [`NearInstant`] time.
+///
+/// The [`impl_stateless!`] macro implements this trait, [`NonRecursive`], and each of
+/// [`IndependentClone`], [`MirroredClone`], and [`MixedClone`] at `NearInstant` speed for a `Copy`
+/// type, in one stroke. (Blanket implementations of the cloning traits for every `Stateless` type
+/// are not possible, as they would overlap with the implementations for `&T`.)
+///
+/// ```
+/// use clone_behavior::{impl_stateless, AnySpeed, IndependentClone, Stateless};
+///
+/// #[derive(Debug, Clone, Copy)]
+/// struct UseDefaults;
+///
+/// impl_stateless!(UseDefaults);
+///
+/// fn assert_stateless<T: Stateless>() {}
+/// assert_stateless::<UseDefaults>();
+/// let _copy = IndependentClone::<AnySpeed>::independent_clone(&UseDefaults);
+/// ```
+///
+/// [independent clone]: IndependentClone
+/// [mirrored clone]: MirroredClone
+/// [`impl_stateless!`]: crate::impl_stateless
+/// [`MixedClone`]: crate::MixedClone
+pub trait Stateless:
+    Copy + NonRecursive + IndependentClone<NearInstant> + MirroredClone<NearInstant>
+{}
+
+
+/// Implement [`Stateless`], [`NonRecursive`], and each of [`IndependentClone`],
+/// [`MirroredClone`], and [`MixedClone`] at [`NearInstant`] speed for the given `Copy` types.
+///
+/// Generic types can be given with their generic parameters in a leading `{for ..}` block, as in
+/// `impl_stateless!({for T: ?Sized} Marker<T>)`.
+///
+/// [`Stateless`]: crate::Stateless
+/// [`NonRecursive`]: crate::NonRecursive
+/// [`IndependentClone`]: crate::IndependentClone
+/// [`MirroredClone`]: crate::MirroredClone
+/// [`MixedClone`]: crate::MixedClone
+/// [`NearInstant`]: crate::NearInstant
+#[macro_export]
+macro_rules! impl_stateless {
+    ($($({for $($bounds:tt)+})? $type:ty),* $(,)?) => {
+        $(
+            impl<$($($bounds)+)?> $crate::Stateless for $type {}
+
+            impl<$($($bounds)+)?> $crate::NonRecursive for $type {}
+
+            impl<$($($bounds)+)?> $crate::IndependentClone<$crate::NearInstant> for $type {
+                #[inline]
+                fn independent_clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl<$($($bounds)+)?> $crate::MirroredClone<$crate::NearInstant> for $type {
+                #[inline]
+                fn mirrored_clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl<$($($bounds)+)?> $crate::MixedClone<$crate::NearInstant> for $type {
+                #[inline]
+                fn mixed_clone(&self) -> Self {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+
+macro_rules! stateless {
+    ($($({for $($bounds:tt)+})? $type:ty),* $(,)?) => {
+        $(
+            impl<$($($bounds)+)?> Stateless for $type {}
+        )*
+    };
+}
+
+stateless! {
+    (),
+    core::convert::Infallible,
+    {for T: ?Sized} core::marker::PhantomData<T>,
+    core::marker::PhantomPinned,
+    core::ops::RangeFull,
+}
+
+#[cfg(feature = "std")]
+#[allow(
+    clippy::std_instead_of_core,
+    reason = "`core::io` is unstable, and only nightly Clippy suggests it",
+)]
+const _: () = {
+    stateless! {
+        std::io::Empty,
+        std::io::Sink,
+    }
+};
@@ -0,0 +1,115 @@
+#![expect(unsafe_code, reason = "declare and implement an unsafe marker trait")]
+#![expect(clippy::absolute_paths, reason = "there's a lot of random types used")]
+
+use crate::call_varargs_macro;
+use crate::{independent::IndependentClone, speed::Speed};
+
+
+/// An [`IndependentClone`] whose independence guarantee may be relied upon for memory safety.
+///
+/// The guarantees of [`IndependentClone`] are only semantic: an incorrect implementation is a bug,
+/// but is not undefined behavior, so unsafe code cannot rely on them. Implementing this trait
+/// promises that the guarantees hold without exception, so that, for instance, a data structure
+/// which hands out aliasing-sensitive handles can rely on an independent clone not aliasing its
+/// source.
+///
+/// # Safety
+/// For every value `x` of the implementing type, and every value `y` returned by
+/// `x.independent_clone()` (or written by `y.independent_clone_from(&x)`), at speed `S`:
+/// - no memory which can be mutated through `y` (including through interior mutability) may be
+///   read or written through `x` or any other value derived from `x`, and vice versa; and
+/// - the exceptions permitted by [`IndependentClone`], such as methods which a type documents as
+///   not being subject to its guarantees, do not apply.
+///
+/// Memory which is never mutated, such as a `&'static str`, may still be shared.
+pub unsafe trait TrustedIndependent<S: Speed>: IndependentClone<S> {}
+
+
+macro_rules! trusted {
+    ($($({for $($bounds:tt)+})? $type:ty),* $(,)?) => {
+        $(
+            // SAFETY: the `IndependentClone` implementations of these types either copy plain
+            // data, or place independent clones of trusted contents into new allocations or cells.
+            unsafe impl<S: Speed, $($($bounds)+)?> TrustedIndependent<S> for $type
+            where
+                $type: IndependentClone<S>,
+            {}
+        )*
+    };
+}
+
+trusted! {
+    i8, i16, i32, i64, i128, isize,
+    u8, u16, u32, u64, u128, usize,
+    f32, f64, bool, char, (),
+    core::convert::Infallible,
+    {for T: ?Sized} core::marker::PhantomData<T>,
+    core::marker::PhantomPinned,
+    core::time::Duration,
+    {for T: TrustedIndependent<S>, const N: usize} [T; N],
+    {for T: TrustedIndependent<S>} Option<T>,
+    {for T: TrustedIndependent<S>, E: TrustedIndependent<S>} Result<T, E>,
+    {for T: TrustedIndependent<S>} core::mem::ManuallyDrop<T>,
+    {for T: TrustedIndependent<S> + Copy} core::cell::Cell<T>,
+    {for T: TrustedIndependent<S>} core::cell::RefCell<T>,
+}
+
+macro_rules! non_zero {
+    ($($num:ident),* $(,)?) => {
+        trusted! {
+            $(core::num::NonZero<$num>,)*
+        }
+    };
+}
+
+non_zero!(
+    i8, i16, i32, i64, i128, isize,
+    u8, u16, u32, u64, u128, usize,
+);
+
+#[cfg(feature = "alloc")]
+trusted! {
+    alloc::boxed::Box<str>,
+    {for T: TrustedIndependent<S>} alloc::boxed::Box<[T]>,
+    {for T: TrustedIndependent<S>} alloc::vec::Vec<T>,
+    {for T: TrustedIndependent<S>} alloc::collections::VecDeque<T>,
+    {for T: TrustedIndependent<S>} alloc::collections::LinkedList<T>,
+    {for T: TrustedIndependent<S> + Ord} alloc::collections::BinaryHeap<T>,
+    {for T: TrustedIndependent<S> + Ord} alloc::collections::BTreeSet<T>,
+    {
+        for K: TrustedIndependent<S> + Ord, V: TrustedIndependent<S>
+    } alloc::collections::BTreeMap<K, V>,
+    {for T: TrustedIndependent<S>} alloc::rc::Rc<T>,
+    {for T: TrustedIndependent<S>} alloc::sync::Arc<T>,
+}
+
+#[cfg(feature = "arrayvec")]
+trusted! {
+    {for T: TrustedIndependent<S>, const CAP: usize} arrayvec::ArrayVec<T, CAP>,
+    {for const CAP: usize} arrayvec::ArrayString<CAP>,
+}
+
+#[cfg(feature = "smallvec")]
+trusted! {
+    {for A: smallvec::Array<Item: TrustedIndependent<S>>} smallvec::SmallVec<A>,
+}
+
+#[cfg(feature = "triomphe")]
+trusted! {
+    {for T: TrustedIndependent<S>} triomphe::Arc<T>,
+    {for T: TrustedIndependent<S>} triomphe::UniqueArc<T>,
+}
+
+macro_rules! tuple {
+    ($($args:ident),+) => {
+        // SAFETY: the `IndependentClone` implementations of tuples independently clone each
+        // element, each of which is trusted.
+        unsafe impl<S: Speed, $($args: TrustedIndependent<S>),+> TrustedIndependent<S>
+        for ($($args,)+)
+        where
+            ($($args,)+): IndependentClone<S>,
+        {}
+    };
+}
+
+call_varargs_macro!(tuple);
@@ -0,0 +1,98 @@
+#![expect(clippy::absolute_paths, reason = "there's a lot of random types used")]
+#![warn(clippy::missing_inline_in_public_items, reason = "almost everything is very short")]
+
+use crate::independent::IndependentClone;
+use crate::speed::{Speed, AtLeastAsSlowAs, ConstantTime, NonBlocking};
+
+
+/// Fallibly get deep clones of a value, which do not share any semantically-important mutable
+/// state.
+///
+/// This is the fallible counterpart of [`IndependentClone`], for types whose independent clones
+/// could fail, such as cells or locks which might already be borrowed or locked. Some of those
+/// types also implement [`IndependentClone`] by panicking (or blocking) when a clone cannot be
+/// immediately performed; this trait should instead be used in code which must not panic.
+///
+/// An independent clone returned by this trait must satisfy the same guarantees as one from
+/// [`IndependentClone`]; read its documentation for more.
+pub trait TryIndependentClone<S: Speed>: Sized {
+    /// The error returned when an independent clone could not be performed.
+    type Error;
+
+    /// Attempt to get a deep clone of a value, which does not share any semantically-important
+    /// mutable state.
+    ///
+    /// Read [`TryIndependentClone`] for more.
+    ///
+    /// # Errors
+    /// Returns an error if an independent clone could not be performed. The conditions under which
+    /// an error is returned depend on the implementation.
+    fn try_independent_clone(&self) -> Result<Self, Self::Error>;
+}
+
+
+impl<S, T> TryIndependentClone<S> for core::cell::RefCell<T>
+where
+    S: AtLeastAsSlowAs<NonBlocking<ConstantTime>>,
+    T: IndependentClone<S>,
+{
+    type Error = core::cell::BorrowError;
+
+    /// # Errors
+    /// Returns an error if the value is currently mutably borrowed.
+    #[inline]
+    fn try_independent_clone(&self) -> Result<Self, Self::Error> {
+        let borrow = self.try_borrow()?;
+        Ok(Self::new(T::independent_clone(&borrow)))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S, T> TryIndependentClone<S> for std::sync::RwLock<T>
+where
+    S: AtLeastAsSlowAs<ConstantTime>,
+    T: IndependentClone<S>,
+{
+    type Error = std::sync::TryLockError<()>;
+
+    /// # Errors
+    /// Returns an error if the `RwLock` is poisoned, or if it is currently write-locked.
+    #[inline]
+    fn try_independent_clone(&self) -> Result<Self, Self::Error> {
+        match self.try_read() {
+            Ok(guard) => Ok(Self::new(T::independent_clone(&guard))),
+            Err(err) => Err(erase_guard(&err)),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S, T> TryIndependentClone<S> for std::sync::Mutex<T>
+where
+    S: AtLeastAsSlowAs<ConstantTime>,
+    T: IndependentClone<S>,
+{
+    type Error = std::sync::TryLockError<()>;
+
+    /// # Errors
+    /// Returns an error if the `Mutex` is poisoned, or if it is currently locked (including by the
+    /// current thread).
+    #[inline]
+    fn try_independent_clone(&self) -> Result<Self, Self::Error> {
+        match self.try_lock() {
+            Ok(guard) => Ok(Self::new(T::independent_clone(&guard))),
+            Err(err) => Err(erase_guard(&err)),
+        }
+    }
+}
+
+/// Drop the lock guard (if any) in a `TryLockError`, so that the error does not borrow the lock.
+#[cfg(feature = "std")]
+fn erase_guard<G>(err: &std::sync::TryLockError<G>) -> std::sync::TryLockError<()> {
+    match err {
+        std::sync::TryLockError::Poisoned(_) => {
+            std::sync::TryLockError::Poisoned(std::sync::PoisonError::new(()))
+        }
+        std::sync::TryLockError::WouldBlock => std::sync::TryLockError::WouldBlock,
+    }
+}
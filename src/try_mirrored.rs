@@ -0,0 +1,77 @@
+#![cfg_attr(
+    feature = "std",
+    expect(clippy::absolute_paths, reason = "there's a lot of random types used"),
+)]
+#![warn(clippy::missing_inline_in_public_items, reason = "almost everything is very short")]
+
+use crate::speed::Speed;
+#[cfg(feature = "std")]
+use crate::speed::{AtLeastAsSlowAs, ConstantTime};
+
+
+/// Fallibly get clones that share all semantically-important mutable state.
+///
+/// This is the fallible counterpart of [`MirroredClone`], for handle types whose duplication could
+/// fail, such as operating system resources duplicated with `dup` or `try_clone`.
+///
+/// A mirrored clone returned by this trait must satisfy the same guarantees as one from
+/// [`MirroredClone`]; read its documentation for more.
+///
+/// [`MirroredClone`]: crate::MirroredClone
+pub trait TryMirroredClone<S: Speed>: Sized {
+    /// The error returned when a mirrored clone could not be performed.
+    type Error;
+
+    /// Attempt to get a clone that shares all semantically-important mutable state with its
+    /// source.
+    ///
+    /// Read [`TryMirroredClone`] for more.
+    ///
+    /// # Errors
+    /// Returns an error if a mirrored clone could not be performed. The conditions under which an
+    /// error is returned depend on the implementation.
+    fn try_mirrored_clone(&self) -> Result<Self, Self::Error>;
+}
+
+
+/// Implement `TryMirroredClone<S>` for every speed `S` which is at least as slow as
+/// `ConstantTime`, by calling the type's `try_clone` method.
+#[cfg(feature = "std")]
+macro_rules! try_clone {
+    ($($(#[$meta:meta])* $type:ty),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            impl<S: AtLeastAsSlowAs<ConstantTime>> TryMirroredClone<S> for $type {
+                type Error = std::io::Error;
+
+                /// # Errors
+                /// Returns an error if the operating system fails to duplicate the handle.
+                #[inline]
+                fn try_mirrored_clone(&self) -> Result<Self, Self::Error> {
+                    self.try_clone()
+                }
+            }
+        )*
+    };
+}
+
+// Both handles share the file's cursor, and refer to the same underlying file. Likewise, both
+// handles to a socket read from and write to the same connection.
+#[cfg(feature = "std")]
+try_clone! {
+    std::fs::File,
+    std::net::TcpStream,
+    std::net::UdpSocket,
+}
+
+#[cfg(all(feature = "std", unix))]
+try_clone! {
+    std::os::unix::net::UnixStream,
+    std::os::fd::OwnedFd,
+}
+
+#[cfg(all(feature = "std", windows))]
+try_clone! {
+    std::os::windows::io::OwnedHandle,
+    std::os::windows::io::OwnedSocket,
+}
@@ -0,0 +1,89 @@
+use core::fmt::Debug;
+
+use loom::thread;
+
+use crate::{mirrored::MirroredClone, speed::Speed};
+
+
+/// Assert, under the `loom` model checker, that mirrored clones at speed `S` taken concurrently
+/// with mutation all share their state.
+///
+/// In every interleaving explored by `loom`, a value is created with `make`, and a mirrored clone
+/// of it is moved into a second thread. The second thread takes a mirrored clone of that clone and
+/// applies `mutate` to it, while the first thread applies `mutate` to the source and takes another
+/// mirrored clone of it. Once both threads finish, every clone must be observed to be equal to the
+/// source.
+///
+/// The type should use `loom`'s synchronization primitives (for instance, under `cfg(loom)`) for
+/// its concurrent behavior to be model-checked.
+///
+/// # Panics
+/// Panics if the above condition does not hold in some interleaving, or if `loom` detects a
+/// problem such as a deadlock or a data race.
+#[track_caller]
+pub fn assert_mirrored_concurrent<S, T, O>(make: fn() -> T, mutate: fn(&T), observe: fn(&T) -> O)
+where
+    S: Speed + 'static,
+    T: MirroredClone<S> + Send + 'static,
+    O: PartialEq + Debug + 'static,
+{
+    loom::model(move || {
+        let source = make();
+        let handle = source.mirrored_clone();
+
+        let thread = thread::spawn(move || {
+            let clone = handle.mirrored_clone();
+            mutate(&clone);
+            clone
+        });
+
+        mutate(&source);
+        let other_clone = source.mirrored_clone();
+
+        #[expect(clippy::expect_used, reason = "propagate panics from the spawned thread")]
+        let clone = thread.join().expect("the spawned thread should not panic");
+
+        assert_eq!(
+            observe(&clone), observe(&source),
+            "a mirrored clone mutated in another thread should be observed to equal its source",
+        );
+        assert_eq!(
+            observe(&other_clone), observe(&source),
+            "a mirrored clone taken after concurrent mutation should equal its source",
+        );
+    });
+}
+
+/// Assert, under the `loom` model checker, that taking a mirrored clone at speed `S` does not
+/// block on any lock that the type may hold.
+///
+/// In every interleaving explored by `loom`, a value is created with `make`, and `hold` is called
+/// with the value and a callback. `hold` should acquire whatever lock the type uses to guard its
+/// state (for instance, by beginning a write) and call the callback exactly once while holding it.
+/// The callback takes a mirrored clone in a second thread and waits for it to finish; if the
+/// mirrored clone waits for the lock, `loom` detects a deadlock.
+///
+/// This is intended to check `NearInstant` or `NonBlocking` speed claims.
+///
+/// # Panics
+/// Panics if `loom` detects a deadlock or another problem in some interleaving. Note that `loom`
+/// may abort the process while unwinding from a deadlock, rather than only panicking.
+#[track_caller]
+pub fn assert_mirrored_clone_non_blocking<S, T>(make: fn() -> T, hold: fn(&T, &mut dyn FnMut()))
+where
+    S: Speed + 'static,
+    T: MirroredClone<S> + Send + 'static,
+{
+    loom::model(move || {
+        let source = make();
+        let mut handle = Some(source.mirrored_clone());
+
+        hold(&source, &mut || {
+            if let Some(handle) = handle.take() {
+                let thread = thread::spawn(move || drop(handle.mirrored_clone()));
+                #[expect(clippy::expect_used, reason = "propagate panics from the spawned thread")]
+                thread.join().expect("the spawned thread should not panic");
+            }
+        });
+    });
+}
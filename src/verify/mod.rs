@@ -0,0 +1,124 @@
+//! Helpers for testing that implementations of this crate's traits uphold their contracts.
+//!
+//! Each helper clones a value, mutates one side, and compares observations of both sides. The
+//! speed at which to clone must be provided with a turbofish.
+//!
+//! With the `loom` feature, helpers which model-check concurrent mirrored clones with `loom` are
+//! also provided.
+//!
+//! ```
+//! use std::{cell::RefCell, rc::Rc};
+//! use clone_behavior::{ConstantTime, LinearTime};
+//! use clone_behavior::verify::{assert_independent, assert_mirrored};
+//!
+//! assert_independent::<LinearTime, _, _>(vec![1_u8], |vec| vec.push(2), Vec::len);
+//! assert_mirrored::<ConstantTime, _, _>(
+//!     Rc::new(RefCell::new(0_u8)),
+//!     |value| *value.borrow_mut() += 1,
+//!     |value| *value.borrow(),
+//! );
+//! ```
+
+#![expect(
+    clippy::impl_trait_in_params,
+    reason = "allows the speed to be given with a turbofish, without listing every generic",
+)]
+
+#[cfg(feature = "loom")]
+mod concurrent;
+
+
+use core::fmt::Debug;
+
+use crate::{independent::IndependentClone, mirrored::MirroredClone, speed::Speed};
+
+#[cfg(feature = "loom")]
+pub use self::concurrent::{assert_mirrored_clone_non_blocking, assert_mirrored_concurrent};
+
+
+/// Assert that `value` and its independent clone at speed `S` act independently.
+///
+/// The clone must initially be observed to be equal to `value`. Then, `mutate` is applied to the
+/// clone, which must change how the clone is observed without changing how `value` is observed;
+/// lastly, `mutate` is applied to `value`, which must not change how the clone is observed.
+///
+/// # Panics
+/// Panics if any of the above conditions do not hold, including if `mutate` has no observable
+/// effect (since the test would otherwise be vacuous).
+#[track_caller]
+pub fn assert_independent<S, T, O>(
+    value:      T,
+    mut mutate: impl FnMut(&mut T),
+    observe:    impl Fn(&T) -> O,
+)
+where
+    S: Speed,
+    T: IndependentClone<S>,
+    O: PartialEq + Debug,
+{
+    let mut source = value;
+    let mut clone = source.independent_clone();
+    let source_before = observe(&source);
+    assert_eq!(
+        observe(&clone), source_before,
+        "an independent clone should initially be observed to be equal to its source",
+    );
+
+    mutate(&mut clone);
+    let clone_after = observe(&clone);
+    assert_ne!(clone_after, source_before, "`mutate` should have an observable effect");
+    assert_eq!(
+        observe(&source), source_before,
+        "mutating an independent clone should not affect its source",
+    );
+
+    mutate(&mut source);
+    assert_eq!(
+        observe(&clone), clone_after,
+        "mutating the source of an independent clone should not affect the clone",
+    );
+}
+
+/// Assert that `value` and its mirrored clone at speed `S` share their state.
+///
+/// The clone must initially be observed to be equal to `value`. Then, `mutate` is applied to the
+/// clone, which must change how the clone is observed, and `value` must be observed to be equal
+/// to the clone; lastly, `mutate` is applied to `value`, after which the clone must again be
+/// observed to be equal to `value`.
+///
+/// # Panics
+/// Panics if any of the above conditions do not hold, including if `mutate` has no observable
+/// effect (since the test would otherwise be vacuous).
+#[track_caller]
+pub fn assert_mirrored<S, T, O>(
+    value:      T,
+    mut mutate: impl FnMut(&mut T),
+    observe:    impl Fn(&T) -> O,
+)
+where
+    S: Speed,
+    T: MirroredClone<S>,
+    O: PartialEq + Debug,
+{
+    let mut source = value;
+    let mut clone = source.mirrored_clone();
+    let source_before = observe(&source);
+    assert_eq!(
+        observe(&clone), source_before,
+        "a mirrored clone should initially be observed to be equal to its source",
+    );
+
+    mutate(&mut clone);
+    let clone_after = observe(&clone);
+    assert_ne!(clone_after, source_before, "`mutate` should have an observable effect");
+    assert_eq!(
+        observe(&source), clone_after,
+        "mutating a mirrored clone should be reflected by its source",
+    );
+
+    mutate(&mut source);
+    assert_eq!(
+        observe(&clone), observe(&source),
+        "mutating the source of a mirrored clone should be reflected by the clone",
+    );
+}
@@ -0,0 +1,196 @@
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+use core::ops::{Deref, DerefMut};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use alloc::sync::Arc;
+
+use crate::{independent::IndependentClone, mirrored::MirroredClone, mixed::MixedClone, trace_clone};
+use crate::speed::Speed;
+
+
+/// A wrapper which counts how many clones of each mode are performed.
+///
+/// `Counted<T>` implements [`IndependentClone`], [`MirroredClone`], and [`MixedClone`] at the same
+/// speeds as `T`, by delegating to `T` while incrementing a counter for the corresponding mode.
+/// The counters are shared by a value and all of its clones (of any mode), so [`counts`] reports
+/// the total number of clones performed starting from the original value.
+///
+/// This is intended for tests and diagnostics; for instance, asserting how many deep clones a
+/// data pipeline performs.
+///
+/// ```
+/// use clone_behavior::{Counted, IndependentClone, LinearTime};
+///
+/// let data = Counted::new(vec![1_u8, 2, 3]);
+/// let copy = IndependentClone::<LinearTime>::independent_clone(&data);
+/// assert_eq!(*copy, [1, 2, 3]);
+/// assert_eq!(data.counts().independent, 1);
+/// assert_eq!(data.counts().mirrored, 0);
+/// ```
+///
+/// [`counts`]: Counted::counts
+pub struct Counted<T> {
+    value:    T,
+    counters: Arc<Counters>,
+}
+
+/// A snapshot of the number of clones of each mode performed by a [`Counted`] value and its
+/// clones.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CloneCounts {
+    /// The number of independent clones performed.
+    pub independent: usize,
+    /// The number of mirrored clones performed.
+    pub mirrored:    usize,
+    /// The number of mixed clones performed.
+    pub mixed:       usize,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    independent: AtomicUsize,
+    mirrored:    AtomicUsize,
+    mixed:       AtomicUsize,
+}
+
+impl<T> Counted<T> {
+    /// Wrap a value, with all of its clone counts starting at zero.
+    #[inline]
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            counters: Arc::default(),
+        }
+    }
+
+    /// Unwrap the inner value.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Get the number of clones of each mode performed by this value and any value it shares
+    /// counters with.
+    #[inline]
+    #[must_use]
+    pub fn counts(&self) -> CloneCounts {
+        CloneCounts {
+            independent: self.counters.independent.load(Ordering::Relaxed),
+            mirrored:    self.counters.mirrored.load(Ordering::Relaxed),
+            mixed:       self.counters.mixed.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Reset the shared clone counts to zero.
+    #[inline]
+    pub fn reset_counts(&self) {
+        self.counters.independent.store(0, Ordering::Relaxed);
+        self.counters.mirrored.store(0, Ordering::Relaxed);
+        self.counters.mixed.store(0, Ordering::Relaxed);
+    }
+
+    /// Wrap a value produced by cloning `self`, sharing the counters of `self`.
+    #[inline]
+    fn with_value(&self, value: T) -> Self {
+        Self {
+            value,
+            counters: Arc::clone(&self.counters),
+        }
+    }
+}
+
+/// The clone shares the counters of its source. The counters of a value cloned into with
+/// `independent_clone_from` are left unchanged, so that the last reference to them is not dropped.
+///
+/// # Exceptions
+/// The counters are shared mutable state. They can be observed through `Counted::counts` and
+/// modified through `Counted::reset_counts`, neither of which is subject to the guarantees of
+/// `IndependentClone`.
+impl<S: Speed, T: IndependentClone<S>> IndependentClone<S> for Counted<T> {
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        trace_clone!("independent", S);
+        self.counters.independent.fetch_add(1, Ordering::Relaxed);
+        self.with_value(self.value.independent_clone())
+    }
+
+    #[inline]
+    fn independent_clone_from(&mut self, source: &Self) {
+        trace_clone!("independent", S);
+        source.counters.independent.fetch_add(1, Ordering::Relaxed);
+        self.value.independent_clone_from(&source.value);
+    }
+}
+
+impl<S: Speed, T: MirroredClone<S>> MirroredClone<S> for Counted<T> {
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        trace_clone!("mirrored", S);
+        self.counters.mirrored.fetch_add(1, Ordering::Relaxed);
+        self.with_value(self.value.mirrored_clone())
+    }
+}
+
+impl<S: Speed, T: MixedClone<S>> MixedClone<S> for Counted<T> {
+    #[inline]
+    fn mixed_clone(&self) -> Self {
+        trace_clone!("mixed", S);
+        self.counters.mixed.fetch_add(1, Ordering::Relaxed);
+        self.with_value(self.value.mixed_clone())
+    }
+}
+
+impl<T> From<T> for Counted<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> Deref for Counted<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Counted<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T> AsRef<T> for Counted<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> AsMut<T> for Counted<T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: Default> Default for Counted<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Debug> Debug for Counted<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Counted")
+            .field("value", &self.value)
+            .field("counters", &self.counters)
+            .finish()
+    }
+}
@@ -0,0 +1,153 @@
+use core::{cmp::Ordering, marker::PhantomData};
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+
+use crate::{independent::IndependentClone, trace_clone};
+use crate::speed::{Speed, AnySpeed};
+
+
+/// A transparent wrapper whose [`Clone`] implementation performs an [independent clone] at speed
+/// `S`.
+///
+/// This allows values whose clones are known to be independent to be used with APIs and derives
+/// which only know about [`Clone`], while keeping that guarantee in the type.
+///
+/// [independent clone]: IndependentClone
+#[repr(transparent)]
+pub struct Independent<T, S = AnySpeed> {
+    value: T,
+    _speed: PhantomData<S>,
+}
+
+impl<T, S> Independent<T, S> {
+    /// Wrap a value, so that its [`Clone`] implementation performs an independent clone.
+    #[inline]
+    #[must_use]
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            _speed: PhantomData,
+        }
+    }
+
+    /// Unwrap the inner value.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: IndependentClone<S>, S: Speed> Clone for Independent<T, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        trace_clone!("independent", S);
+        Self::new(self.value.independent_clone())
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        trace_clone!("independent", S);
+        self.value.independent_clone_from(&source.value);
+    }
+}
+
+impl<T, S, S2> IndependentClone<S2> for Independent<T, S>
+where
+    T:  IndependentClone<S2>,
+    S2: Speed,
+{
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        Self::new(self.value.independent_clone())
+    }
+
+    #[inline]
+    fn independent_clone_from(&mut self, source: &Self) {
+        self.value.independent_clone_from(&source.value);
+    }
+}
+
+impl<T, S> From<T> for Independent<T, S> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T, S> Deref for Independent<T, S> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T, S> DerefMut for Independent<T, S> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T, S> AsRef<T> for Independent<T, S> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T, S> AsMut<T> for Independent<T, S> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: Default, S> Default for Independent<T, S> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Debug, S> Debug for Independent<T, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("Independent").field(&self.value).finish()
+    }
+}
+
+impl<T: PartialEq, S> PartialEq for Independent<T, S> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq, S> Eq for Independent<T, S> {}
+
+impl<T: PartialOrd, S> PartialOrd for Independent<T, S> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Ord, S> Ord for Independent<T, S> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T: Hash, S> Hash for Independent<T, S> {
+    #[inline]
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.value.hash(state);
+    }
+}
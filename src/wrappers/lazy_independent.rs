@@ -0,0 +1,145 @@
+use core::marker::PhantomData;
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+use core::ops::Deref;
+
+use alloc::sync::Arc;
+
+use crate::{independent::IndependentClone, mixed::MixedClone, trace_clone};
+use crate::speed::{Speed, AtLeastAsSlowAs, AnySpeed, ConstantTime};
+
+
+/// A copy-on-write wrapper, whose [mixed clones] share the value until one of them is mutated.
+///
+/// This is a wrapper around an `Arc<T>`, in the style of [`Arc::make_mut`], except that the copy
+/// is made with an [independent clone] at speed `S` instead of with [`Clone`].
+///
+/// # Mixed clone semantics
+/// A mixed clone (or [`Clone`]) of a `LazyIndependent` is a cheap reference-count increment, and
+/// shares the underlying value with its source. Reads through [`Deref`] observe the shared value.
+/// The first call to [`get_mut`] on a value which is shared with another clone performs an
+/// independent clone of `T` and detaches from the other clones, so a mutation of one clone is
+/// never observed by any other clone.
+///
+/// Whether a value is currently shared can be observed with [`ptr_eq`].
+///
+/// An [independent clone] of a `LazyIndependent` eagerly performs the deep copy.
+///
+/// [mixed clones]: MixedClone
+/// [independent clone]: IndependentClone
+/// [`get_mut`]: LazyIndependent::get_mut
+/// [`ptr_eq`]: LazyIndependent::ptr_eq
+pub struct LazyIndependent<T, S = AnySpeed> {
+    value:  Arc<T>,
+    _speed: PhantomData<S>,
+}
+
+impl<T, S> LazyIndependent<T, S> {
+    /// Wrap a value, which is not yet shared with any clone.
+    #[inline]
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        Self {
+            value:  Arc::new(value),
+            _speed: PhantomData,
+        }
+    }
+
+    /// Returns `true` if the two values are mixed clones which currently share the same value.
+    #[inline]
+    #[must_use]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.value, &other.value)
+    }
+}
+
+impl<T: IndependentClone<S>, S: Speed> LazyIndependent<T, S> {
+    /// Get a mutable reference to the value.
+    ///
+    /// If the value is shared with any other clone, an independent clone of the value is first
+    /// made, and this `LazyIndependent` no longer shares its value with the other clones.
+    #[expect(clippy::missing_panics_doc, reason = "the `expect` cannot fail")]
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self) -> &mut T {
+        if Arc::get_mut(&mut self.value).is_none() {
+            self.value = Arc::new(T::independent_clone(&self.value));
+        }
+
+        #[expect(clippy::expect_used, reason = "the `Arc` was just made unique")]
+        Arc::get_mut(&mut self.value).expect("a newly-created `Arc` is unique")
+    }
+
+    /// Unwrap the inner value, making an independent clone of it if it is shared with any other
+    /// clone.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        Arc::try_unwrap(self.value).unwrap_or_else(|value| T::independent_clone(&value))
+    }
+}
+
+impl<T, S> Clone for LazyIndependent<T, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            value:  Arc::clone(&self.value),
+            _speed: PhantomData,
+        }
+    }
+}
+
+impl<T, S, S2: Speed> MixedClone<S2> for LazyIndependent<T, S> {
+    #[inline]
+    fn mixed_clone(&self) -> Self {
+        trace_clone!("mixed", S2);
+        self.clone()
+    }
+}
+
+impl<T, S, S2> IndependentClone<S2> for LazyIndependent<T, S>
+where
+    T:  IndependentClone<S2>,
+    S2: AtLeastAsSlowAs<ConstantTime>,
+{
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        trace_clone!("independent", S2);
+        Self::new(T::independent_clone(&self.value))
+    }
+}
+
+impl<T, S> From<T> for LazyIndependent<T, S> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T, S> Deref for LazyIndependent<T, S> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T, S> AsRef<T> for LazyIndependent<T, S> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: Default, S> Default for LazyIndependent<T, S> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Debug, S> Debug for LazyIndependent<T, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("LazyIndependent").field(&self.value).finish()
+    }
+}
@@ -0,0 +1,19 @@
+mod independent;
+mod with_speed;
+#[cfg(feature = "alloc")]
+mod shared;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+mod counted;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+mod lazy_independent;
+
+
+pub use self::{independent::Independent, with_speed::WithSpeed};
+#[cfg(feature = "alloc")]
+pub use self::shared::LocalShared;
+#[cfg(feature = "std")]
+pub use self::shared::Shared;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use self::counted::{CloneCounts, Counted};
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use self::lazy_independent::LazyIndependent;
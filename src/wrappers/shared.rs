@@ -0,0 +1,264 @@
+use core::cell::{BorrowError, BorrowMutError, Ref, RefCell, RefMut};
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+
+use alloc::rc::Rc;
+#[cfg(feature = "std")]
+use alloc::sync::Arc;
+#[cfg(feature = "std")]
+use std::sync::{LockResult, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockResult};
+
+use crate::{blanket_impls::NonRecursive, independent::IndependentClone, mirrored::MirroredClone};
+use crate::snapshot::Snapshot;
+use crate::speed::{AtLeastAsSlowAs, ConstantTime, NearInstant, NonBlocking};
+
+
+/// A thread-safe, shared, mutable value, whose clones are [mirrored clones].
+///
+/// This is a wrapper around an `Arc<RwLock<T>>`. Every clone refers to the same value, so a write
+/// through one clone is visible through every other clone. For a single-threaded equivalent,
+/// see [`LocalShared`].
+///
+/// [mirrored clones]: MirroredClone
+#[cfg(feature = "std")]
+pub struct Shared<T: ?Sized>(Arc<RwLock<T>>);
+
+#[cfg(feature = "std")]
+impl<T> Shared<T> {
+    /// Create a new shared value.
+    #[inline]
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        Self(Arc::new(RwLock::new(value)))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized> Shared<T> {
+    /// Lock the shared value with shared read access, blocking the current thread until it can be
+    /// acquired.
+    ///
+    /// # Errors
+    /// Returns an error if the lock is poisoned. Read [`RwLock::read`] for more.
+    #[inline]
+    pub fn read(&self) -> LockResult<RwLockReadGuard<'_, T>> {
+        self.0.read()
+    }
+
+    /// Lock the shared value with exclusive write access, blocking the current thread until it
+    /// can be acquired.
+    ///
+    /// # Errors
+    /// Returns an error if the lock is poisoned. Read [`RwLock::write`] for more.
+    #[inline]
+    pub fn write(&self) -> LockResult<RwLockWriteGuard<'_, T>> {
+        self.0.write()
+    }
+
+    /// Attempt to lock the shared value with shared read access, without blocking.
+    ///
+    /// # Errors
+    /// Returns an error if the lock is poisoned or is currently write-locked. Read
+    /// [`RwLock::try_read`] for more.
+    #[inline]
+    pub fn try_read(&self) -> TryLockResult<RwLockReadGuard<'_, T>> {
+        self.0.try_read()
+    }
+
+    /// Attempt to lock the shared value with exclusive write access, without blocking.
+    ///
+    /// # Errors
+    /// Returns an error if the lock is poisoned or is currently locked. Read
+    /// [`RwLock::try_write`] for more.
+    #[inline]
+    pub fn try_write(&self) -> TryLockResult<RwLockWriteGuard<'_, T>> {
+        self.0.try_write()
+    }
+
+    /// Returns `true` if the two values are mirrored clones which refer to the same shared value.
+    #[inline]
+    #[must_use]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.0, &other.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized> Clone for Shared<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized> MirroredClone<NearInstant> for Shared<T> {
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        self.clone()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized> NonRecursive for Shared<T> {}
+
+#[cfg(feature = "std")]
+impl<S, T> Snapshot<S> for Shared<T>
+where
+    S: AtLeastAsSlowAs<ConstantTime>,
+    T: IndependentClone<S>,
+{
+    type Snapshot = T;
+
+    /// # Panics
+    /// Panics if the lock is poisoned.
+    #[inline]
+    fn snapshot(&self) -> Self::Snapshot {
+        let lock_result: LockResult<_> = self.read();
+        #[expect(clippy::unwrap_used, reason = "Unwrapping poison")]
+        T::independent_clone(&lock_result.unwrap())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> From<T> for Shared<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Default> Default for Shared<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized + Debug> Debug for Shared<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("Shared").field(&self.0).finish()
+    }
+}
+
+/// A single-threaded, shared, mutable value, whose clones are [mirrored clones].
+///
+/// This is a wrapper around an `Rc<RefCell<T>>`. Every clone refers to the same value, so a write
+/// through one clone is visible through every other clone. For a thread-safe equivalent, see
+/// `Shared`.
+///
+/// [mirrored clones]: MirroredClone
+pub struct LocalShared<T: ?Sized>(Rc<RefCell<T>>);
+
+impl<T> LocalShared<T> {
+    /// Create a new shared value.
+    #[inline]
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        Self(Rc::new(RefCell::new(value)))
+    }
+}
+
+impl<T: ?Sized> LocalShared<T> {
+    /// Immutably borrow the shared value.
+    ///
+    /// # Panics
+    /// Panics if the value is currently mutably borrowed. Use [`try_read`] to instead get an
+    /// error.
+    ///
+    /// [`try_read`]: LocalShared::try_read
+    #[inline]
+    #[must_use]
+    pub fn read(&self) -> Ref<'_, T> {
+        self.0.borrow()
+    }
+
+    /// Mutably borrow the shared value.
+    ///
+    /// # Panics
+    /// Panics if the value is currently borrowed. Use [`try_write`] to instead get an error.
+    ///
+    /// [`try_write`]: LocalShared::try_write
+    #[inline]
+    #[must_use]
+    pub fn write(&self) -> RefMut<'_, T> {
+        self.0.borrow_mut()
+    }
+
+    /// Attempt to immutably borrow the shared value.
+    ///
+    /// # Errors
+    /// Returns an error if the value is currently mutably borrowed.
+    #[inline]
+    pub fn try_read(&self) -> Result<Ref<'_, T>, BorrowError> {
+        self.0.try_borrow()
+    }
+
+    /// Attempt to mutably borrow the shared value.
+    ///
+    /// # Errors
+    /// Returns an error if the value is currently borrowed.
+    #[inline]
+    pub fn try_write(&self) -> Result<RefMut<'_, T>, BorrowMutError> {
+        self.0.try_borrow_mut()
+    }
+
+    /// Returns `true` if the two values are mirrored clones which refer to the same shared value.
+    #[inline]
+    #[must_use]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Rc::ptr_eq(&this.0, &other.0)
+    }
+}
+
+impl<T: ?Sized> Clone for LocalShared<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+}
+
+impl<T: ?Sized> MirroredClone<NearInstant> for LocalShared<T> {
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl<T: ?Sized> NonRecursive for LocalShared<T> {}
+
+impl<S, T> Snapshot<S> for LocalShared<T>
+where
+    S: AtLeastAsSlowAs<NonBlocking<ConstantTime>>,
+    T: IndependentClone<S>,
+{
+    type Snapshot = T;
+
+    /// # Panics
+    /// Panics if the value is currently mutably borrowed.
+    #[inline]
+    fn snapshot(&self) -> Self::Snapshot {
+        T::independent_clone(&self.read())
+    }
+}
+
+impl<T> From<T> for LocalShared<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Default> Default for LocalShared<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for LocalShared<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("LocalShared").field(&self.0).finish()
+    }
+}
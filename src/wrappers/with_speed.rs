@@ -0,0 +1,222 @@
+use core::{cmp::Ordering, marker::PhantomData};
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+
+use crate::{independent::IndependentClone, mirrored::MirroredClone, mixed::MixedClone, trace_clone};
+use crate::speed::{Speed, AtLeastAsSlowAs};
+
+
+/// A transparent wrapper which fixes the speed at which a value is cloned to `S`.
+///
+/// When a type implements a cloning trait at several speeds, calling a method like
+/// [`independent_clone`] on it can fail to infer which speed to use. `WithSpeed` provides inherent
+/// `independent_clone`, `mirrored_clone`, and `mixed_clone` methods, which take precedence over
+/// the trait methods and always clone at speed `S`.
+///
+/// `WithSpeed<S, T>` also implements each cloning trait implemented by `T` at speed `S`, at every
+/// speed which is [`AtLeastAsSlowAs`] `S`, always cloning the inner value at speed `S`. This allows
+/// the value to be passed to generic code, or to wrappers like [`Independent`].
+///
+/// [`independent_clone`]: IndependentClone::independent_clone
+/// [`Independent`]: crate::Independent
+#[repr(transparent)]
+pub struct WithSpeed<S, T> {
+    value: T,
+    _speed: PhantomData<S>,
+}
+
+impl<S, T> WithSpeed<S, T> {
+    /// Wrap a value, fixing the speed at which it is cloned to `S`.
+    #[inline]
+    #[must_use]
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            _speed: PhantomData,
+        }
+    }
+
+    /// Unwrap the inner value.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+#[expect(
+    clippy::same_name_method,
+    reason = "the inherent methods take precedence over the trait methods, fixing the speed",
+)]
+impl<S, T> WithSpeed<S, T> {
+    /// Get an independent clone of the value, at speed `S`.
+    ///
+    /// Read [`IndependentClone`] for more.
+    #[inline]
+    #[must_use]
+    pub fn independent_clone(&self) -> Self
+    where
+        S: Speed,
+        T: IndependentClone<S>,
+    {
+        trace_clone!("independent", S);
+        Self::new(self.value.independent_clone())
+    }
+
+    /// Get a mirrored clone of the value, at speed `S`.
+    ///
+    /// Read [`MirroredClone`] for more.
+    #[inline]
+    #[must_use]
+    pub fn mirrored_clone(&self) -> Self
+    where
+        S: Speed,
+        T: MirroredClone<S>,
+    {
+        trace_clone!("mirrored", S);
+        Self::new(self.value.mirrored_clone())
+    }
+
+    /// Get a mixed clone of the value, at speed `S`.
+    ///
+    /// Read [`MixedClone`] for more.
+    #[inline]
+    #[must_use]
+    pub fn mixed_clone(&self) -> Self
+    where
+        S: Speed,
+        T: MixedClone<S>,
+    {
+        trace_clone!("mixed", S);
+        Self::new(self.value.mixed_clone())
+    }
+}
+
+impl<S, S2, T> IndependentClone<S2> for WithSpeed<S, T>
+where
+    S:  Speed,
+    S2: AtLeastAsSlowAs<S>,
+    T:  IndependentClone<S>,
+{
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        trace_clone!("independent", S2);
+        Self::new(self.value.independent_clone())
+    }
+
+    #[inline]
+    fn independent_clone_from(&mut self, source: &Self) {
+        trace_clone!("independent", S2);
+        self.value.independent_clone_from(&source.value);
+    }
+}
+
+impl<S, S2, T> MirroredClone<S2> for WithSpeed<S, T>
+where
+    S:  Speed,
+    S2: AtLeastAsSlowAs<S>,
+    T:  MirroredClone<S>,
+{
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        trace_clone!("mirrored", S2);
+        Self::new(self.value.mirrored_clone())
+    }
+}
+
+impl<S, S2, T> MixedClone<S2> for WithSpeed<S, T>
+where
+    S:  Speed,
+    S2: AtLeastAsSlowAs<S>,
+    T:  MixedClone<S>,
+{
+    #[inline]
+    fn mixed_clone(&self) -> Self {
+        trace_clone!("mixed", S2);
+        Self::new(self.value.mixed_clone())
+    }
+}
+
+impl<S, T> From<T> for WithSpeed<S, T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<S, T> Deref for WithSpeed<S, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<S, T> DerefMut for WithSpeed<S, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<S, T> AsRef<T> for WithSpeed<S, T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<S, T> AsMut<T> for WithSpeed<S, T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<S, T: Default> Default for WithSpeed<S, T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<S, T: Debug> Debug for WithSpeed<S, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("WithSpeed").field(&self.value).finish()
+    }
+}
+
+impl<S, T: PartialEq> PartialEq for WithSpeed<S, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<S, T: Eq> Eq for WithSpeed<S, T> {}
+
+impl<S, T: PartialOrd> PartialOrd for WithSpeed<S, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<S, T: Ord> Ord for WithSpeed<S, T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<S, T: Hash> Hash for WithSpeed<S, T> {
+    #[inline]
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.value.hash(state);
+    }
+}